@@ -0,0 +1,296 @@
+//! Anonymization policy for data sent to LLMs.
+//!
+//! The `anonymization` section lists what is masked in ticket content before
+//! it leaves the machine: built-in entity types, custom regex rules, and
+//! per-field allow-lists of values kept as they are:
+//!
+//! ```yaml
+//! anonymization:
+//!   strict: true
+//!   entities: [email, name, ticket_key, hostname, iban]
+//!   rules:
+//!     - name: employee_id
+//!       pattern: "EMP-[0-9]{6}"
+//!       replacement: "[EMPLOYEE]"
+//!   allow:
+//!     reporter: ["qa-bot@example.com"]
+//! ```
+//!
+//! Rule patterns use the syntax of the [`regex`] crate and are compiled when
+//! the configuration is validated; an invalid pattern is reported with the
+//! parser message and column: `EMP-(\d{6}` gives `field
+//! 'anonymization.rules.0.pattern' is not a valid regular expression: unclosed
+//! group (at column 5)`.
+//!
+//! # Strict Mode
+//!
+//! With `strict: true`, cloud LLM use requires a policy: `llm.mode: cloud`,
+//! or `auto` with `cloud_enabled: true`, is rejected until at least one entity
+//! type or rule is configured. `strict` can live in a shared fragment (see
+//! [`include`](mod@crate::include)) so every project including it has to define
+//! its policy before enabling the cloud.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{is_valid_identifier, LlmConfig, LlmMode};
+use crate::error::ConfigError;
+
+/// Anonymization policy configuration.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AnonymizationConfig {
+    /// Reject cloud LLM use while no entity type or rule is configured (default: false)
+    #[serde(default)]
+    pub strict: bool,
+
+    /// Built-in entity types to mask
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entities: Vec<EntityType>,
+
+    /// Custom masking rules, applied after the entity types
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<AnonymizationRule>,
+
+    /// Values kept unmasked, by field name (e.g. `reporter`, `summary`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub allow: BTreeMap<String, Vec<String>>,
+}
+
+/// Built-in entity type recognized by the anonymizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityType {
+    /// Email addresses
+    Email,
+    /// Person names
+    Name,
+    /// Ticket keys (e.g. `PROJ-123`)
+    TicketKey,
+    /// Host names and IP addresses
+    Hostname,
+    /// International bank account numbers
+    Iban,
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntityType::Email => write!(f, "email"),
+            EntityType::Name => write!(f, "name"),
+            EntityType::TicketKey => write!(f, "ticket_key"),
+            EntityType::Hostname => write!(f, "hostname"),
+            EntityType::Iban => write!(f, "iban"),
+        }
+    }
+}
+
+/// Custom masking rule.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AnonymizationRule {
+    /// Unique rule name (alphanumeric, hyphens and underscores)
+    pub name: String,
+
+    /// Regular expression matching the text to mask
+    pub pattern: String,
+
+    /// Text replacing each match (default: the rule name in brackets, e.g. `[employee_id]`);
+    /// `$1` or `${1}` insert a capture group, so the value is not interpolated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
+}
+
+impl AnonymizationConfig {
+    /// Whether the policy masks anything (at least one entity type or rule).
+    pub fn has_policy(&self) -> bool {
+        !self.entities.is_empty() || !self.rules.is_empty()
+    }
+
+    /// Whether `value` is allowed unmasked in `field`.
+    pub fn is_allowed(&self, field: &str, value: &str) -> bool {
+        self.allow
+            .get(field)
+            .is_some_and(|values| values.iter().any(|allowed| allowed == value))
+    }
+}
+
+impl AnonymizationRule {
+    /// Text replacing each match of the rule.
+    pub fn replacement(&self) -> String {
+        match &self.replacement {
+            Some(replacement) => replacement.clone(),
+            None => format!("[{}]", self.name),
+        }
+    }
+}
+
+/// Validate the anonymization section, pushing at most one error per field.
+pub(crate) fn validate_anonymization(
+    anonymization: &AnonymizationConfig,
+    errors: &mut Vec<ConfigError>,
+) {
+    let mut seen_entities = HashSet::new();
+    for (index, entity) in anonymization.entities.iter().enumerate() {
+        if !seen_entities.insert(entity) {
+            errors.push(ConfigError::invalid_value(
+                format!("anonymization.entities.{}", index),
+                format!("lists '{}' more than once", entity),
+                "each entity type once",
+            ));
+        }
+    }
+
+    let mut seen_rules = HashSet::new();
+    for (index, rule) in anonymization.rules.iter().enumerate() {
+        let field = |name: &str| format!("anonymization.rules.{}.{}", index, name);
+        if !is_valid_identifier(&rule.name) {
+            errors.push(ConfigError::invalid_value(
+                field("name"),
+                "must be a non-empty identifier",
+                "alphanumeric characters, hyphens (-) and underscores (_) only (e.g., 'employee_id')",
+            ));
+        } else if !seen_rules.insert(rule.name.as_str()) {
+            errors.push(ConfigError::invalid_value(
+                field("name"),
+                format!("duplicates the rule name '{}'", rule.name),
+                "a unique name for each rule",
+            ));
+        }
+        if rule.pattern.is_empty() {
+            errors.push(ConfigError::invalid_value(
+                field("pattern"),
+                "cannot be empty",
+                "a regular expression like 'EMP-[0-9]{6}'",
+            ));
+        } else if let Err(e) = Regex::new(&rule.pattern) {
+            errors.push(ConfigError::invalid_value(
+                field("pattern"),
+                format!("is not a valid regular expression: {}", regex_error_message(&e)),
+                "a regular expression in the syntax of the Rust regex crate (escape '\\' as '\\\\' in double-quoted YAML strings)",
+            ));
+        }
+    }
+
+    for (name, values) in &anonymization.allow {
+        if let Some(index) = values.iter().position(|v| v.trim().is_empty()) {
+            errors.push(ConfigError::invalid_value(
+                format!("anonymization.allow.{}.{}", name, index),
+                "cannot be empty",
+                "a value to keep unmasked in this field",
+            ));
+        }
+    }
+}
+
+/// Reject cloud LLM use when strict mode is on and no policy is configured.
+pub(crate) fn validate_strict_mode(
+    anonymization: Option<&AnonymizationConfig>,
+    llm: Option<&LlmConfig>,
+    errors: &mut Vec<ConfigError>,
+) {
+    let (Some(anonymization), Some(llm)) = (anonymization, llm) else {
+        return;
+    };
+    let uses_cloud = llm.mode == LlmMode::Cloud || (llm.mode == LlmMode::Auto && llm.cloud_enabled);
+    if anonymization.strict && uses_cloud && !anonymization.has_policy() {
+        errors.push(ConfigError::invalid_value(
+            "llm.mode",
+            format!(
+                "cannot be '{}' with cloud enabled while anonymization.strict is set and no anonymization policy is configured",
+                llm.mode
+            ),
+            "list entity types or rules under 'anonymization', or use mode: local",
+        ));
+    }
+}
+
+/// One-line message for a pattern that does not compile.
+///
+/// Parse errors render as `regex parse error:`, the pattern, a caret line and
+/// `error: <message>`; they are folded into `<message> (at column N)`.
+fn regex_error_message(error: &regex::Error) -> String {
+    let regex::Error::Syntax(text) = error else {
+        return error.to_string();
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    let message = lines
+        .iter()
+        .rev()
+        .find_map(|line| line.strip_prefix("error: "))
+        .unwrap_or(text.as_str());
+    // The pattern line and the caret line share the same indentation
+    let column = lines
+        .iter()
+        .zip(lines.iter().skip(1))
+        .find_map(|(pattern, carets)| {
+            let indent = pattern.len() - pattern.trim_start().len();
+            let caret = carets.find('^')?;
+            (carets.trim().chars().all(|c| c == '^') && caret >= indent)
+                .then(|| {
+                    pattern[indent..]
+                        .get(..caret - indent)
+                        .map(|s| s.chars().count() + 1)
+                })
+                .flatten()
+        });
+    match column {
+        Some(column) => format!("{} (at column {})", message, column),
+        None => message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anonymization(yaml: &str) -> AnonymizationConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_invalid_pattern_reports_message_and_column() {
+        let config = anonymization(
+            "rules:\n  - name: employee_id\n    pattern: \"EMP-(\\\\d{6}\"\n  - name: employee_id\n    pattern: \"[a-z]+\"\n",
+        );
+        let mut errors = Vec::new();
+        validate_anonymization(&config, &mut errors);
+
+        let fields: Vec<&str> = errors.iter().filter_map(|e| e.field()).collect();
+        assert_eq!(
+            fields,
+            [
+                "anonymization.rules.0.pattern",
+                "anonymization.rules.1.name"
+            ]
+        );
+        let message = errors[0].to_string();
+        assert!(
+            message.contains("is not a valid regular expression: unclosed group (at column 5)"),
+            "{}",
+            message
+        );
+        assert!(!message.contains('\n'), "{}", message);
+    }
+
+    #[test]
+    fn test_strict_mode_requires_a_policy_for_cloud() {
+        let llm: LlmConfig = serde_yaml::from_str("mode: auto\ncloud_enabled: true\n").unwrap();
+        let strict = anonymization("strict: true\n");
+        let mut errors = Vec::new();
+        validate_strict_mode(Some(&strict), Some(&llm), &mut errors);
+        assert_eq!(errors[0].field(), Some("llm.mode"));
+
+        let with_policy = anonymization("strict: true\nentities: [email, ticket_key]\n");
+        let local: LlmConfig = serde_yaml::from_str("mode: local\n").unwrap();
+        let mut errors = Vec::new();
+        validate_strict_mode(Some(&with_policy), Some(&llm), &mut errors);
+        validate_strict_mode(Some(&strict), Some(&local), &mut errors);
+        assert!(errors.is_empty());
+        assert!(with_policy.has_policy());
+    }
+}
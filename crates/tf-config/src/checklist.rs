@@ -0,0 +1,293 @@
+//! Testability checklist and Go/Clarify/No-Go scoring rules.
+//!
+//! The `checklist` section lists the criteria a ticket is checked against
+//! before test design starts. Each criterion has a weight (weights sum to 100)
+//! and may be blocking; the score of a ticket is the sum of the weights of the
+//! criteria it meets:
+//!
+//! ```yaml
+//! checklist:
+//!   criteria:
+//!     - id: acceptance_criteria
+//!       label: "Acceptance criteria are defined"
+//!       weight: 40
+//!       blocking: true
+//!     - id: test_data
+//!       label: "Test data is identified"
+//!       weight: 35
+//!     - id: environments
+//!       label: "Target environments are listed"
+//!       weight: 25
+//!   thresholds:
+//!     go: 80       # score >= 80: Go
+//!     clarify: 50  # score >= 50: Clarify, below: No-Go
+//! ```
+//!
+//! A ticket failing a blocking criterion is No-Go whatever its score.
+//! Profiles can override the section with `checklist:` (see
+//! [`ChecklistOverride`](crate::ChecklistOverride)); `criteria` is replaced as
+//! a whole list, thresholds field by field under `merge: deep`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::{load_config, Decision};
+//!
+//! let config = load_config(Path::new("config.yaml"))?;
+//! if let Some(checklist) = &config.checklist {
+//!     let met = ["acceptance_criteria", "test_data"];
+//!     assert_eq!(checklist.score(&met), 75);
+//!     assert_eq!(checklist.decision(&met), Decision::Clarify);
+//! }
+//! # Ok::<(), tf_config::ConfigError>(())
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::config::is_valid_identifier;
+use crate::error::ConfigError;
+
+/// Sum that the criterion weights must reach.
+pub const TOTAL_WEIGHT: u32 = 100;
+
+/// Testability checklist configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ChecklistConfig {
+    /// Criteria checked for each ticket, in display order
+    pub criteria: Vec<ChecklistCriterion>,
+
+    /// Score thresholds for the Go/Clarify/No-Go decision
+    #[serde(default)]
+    pub thresholds: ScoreThresholds,
+}
+
+/// One criterion of the testability checklist.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ChecklistCriterion {
+    /// Unique identifier (alphanumeric, hyphens and underscores)
+    pub id: String,
+
+    /// Human-readable description shown in reports
+    pub label: String,
+
+    /// Weight of the criterion in the score; weights sum to 100
+    pub weight: u32,
+
+    /// Whether failing this criterion makes the ticket No-Go (default: false)
+    #[serde(default)]
+    pub blocking: bool,
+}
+
+/// Minimum scores for the Go and Clarify decisions, out of 100.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScoreThresholds {
+    /// Minimum score for Go (default: 80)
+    #[serde(default = "default_go_threshold")]
+    pub go: u32,
+
+    /// Minimum score for Clarify; lower scores are No-Go (default: 50)
+    #[serde(default = "default_clarify_threshold")]
+    pub clarify: u32,
+}
+
+impl Default for ScoreThresholds {
+    fn default() -> Self {
+        ScoreThresholds {
+            go: default_go_threshold(),
+            clarify: default_clarify_threshold(),
+        }
+    }
+}
+
+pub(crate) fn default_go_threshold() -> u32 {
+    80
+}
+
+pub(crate) fn default_clarify_threshold() -> u32 {
+    50
+}
+
+/// Outcome of the testability checklist for a ticket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Decision {
+    /// Ready for test design
+    Go,
+    /// Questions to raise before test design
+    Clarify,
+    /// Not testable as written
+    NoGo,
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Decision::Go => write!(f, "Go"),
+            Decision::Clarify => write!(f, "Clarify"),
+            Decision::NoGo => write!(f, "No-Go"),
+        }
+    }
+}
+
+impl ChecklistConfig {
+    /// Sum of the weights of the criteria whose id is in `met`.
+    pub fn score(&self, met: &[&str]) -> u32 {
+        self.criteria
+            .iter()
+            .filter(|criterion| met.contains(&criterion.id.as_str()))
+            .fold(0, |score, criterion| score.saturating_add(criterion.weight))
+    }
+
+    /// Decision for a ticket meeting the criteria in `met`: No-Go if a
+    /// blocking criterion is not met, else according to the thresholds.
+    pub fn decision(&self, met: &[&str]) -> Decision {
+        let blocked = self
+            .criteria
+            .iter()
+            .any(|criterion| criterion.blocking && !met.contains(&criterion.id.as_str()));
+        let score = self.score(met);
+        if blocked {
+            Decision::NoGo
+        } else if score >= self.thresholds.go {
+            Decision::Go
+        } else if score >= self.thresholds.clarify {
+            Decision::Clarify
+        } else {
+            Decision::NoGo
+        }
+    }
+}
+
+/// Validate the checklist section, pushing at most one error per field.
+pub(crate) fn validate_checklist(checklist: &ChecklistConfig, errors: &mut Vec<ConfigError>) {
+    if checklist.criteria.is_empty() {
+        errors.push(ConfigError::missing_field(
+            "checklist.criteria",
+            "at least one criterion with an id, a label and a weight",
+        ));
+        return;
+    }
+
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for (index, criterion) in checklist.criteria.iter().enumerate() {
+        let field = |name: &str| format!("checklist.criteria.{}.{}", index, name);
+        if !is_valid_identifier(&criterion.id) {
+            errors.push(ConfigError::invalid_value(
+                field("id"),
+                "must be a non-empty identifier",
+                "alphanumeric characters, hyphens (-) and underscores (_) only (e.g., 'acceptance_criteria')",
+            ));
+        } else if let Some(&first) = seen.get(criterion.id.as_str()) {
+            errors.push(ConfigError::invalid_value(
+                field("id"),
+                format!(
+                    "duplicates the id '{}' of checklist.criteria.{}",
+                    criterion.id, first
+                ),
+                "a unique id for each criterion",
+            ));
+        } else {
+            seen.insert(&criterion.id, index);
+        }
+        if criterion.label.trim().is_empty() {
+            errors.push(ConfigError::invalid_value(
+                field("label"),
+                "cannot be empty",
+                "a short description like 'Acceptance criteria are defined'",
+            ));
+        }
+        if criterion.weight == 0 {
+            errors.push(ConfigError::invalid_value(
+                field("weight"),
+                "must be a positive integer (greater than 0)",
+                "remove the criterion, or give it a share of the 100 points",
+            ));
+        }
+    }
+
+    let total = checklist
+        .criteria
+        .iter()
+        .fold(0u32, |total, c| total.saturating_add(c.weight));
+    if total != TOTAL_WEIGHT {
+        errors.push(ConfigError::invalid_value(
+            "checklist.criteria",
+            format!("has weights summing to {}", total),
+            format!("weights summing to {}", TOTAL_WEIGHT),
+        ));
+    }
+
+    let thresholds = checklist.thresholds;
+    if thresholds.go > TOTAL_WEIGHT {
+        errors.push(ConfigError::invalid_value(
+            "checklist.thresholds.go",
+            format!("cannot exceed the maximum score ({})", TOTAL_WEIGHT),
+            "a score between 0 and 100 (e.g., 80)",
+        ));
+    }
+    if thresholds.clarify > thresholds.go {
+        errors.push(ConfigError::invalid_value(
+            "checklist.thresholds.clarify",
+            format!("must not be above thresholds.go ({})", thresholds.go),
+            format!("a score of at most {} (e.g., 50)", thresholds.go),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checklist(yaml: &str) -> ChecklistConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn fields(checklist: &ChecklistConfig) -> Vec<String> {
+        let mut errors = Vec::new();
+        validate_checklist(checklist, &mut errors);
+        errors
+            .iter()
+            .map(|e| e.field().unwrap_or_default().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_decision_uses_thresholds_and_blocking_criteria() {
+        let checklist = checklist(
+            "criteria:\n  - id: ac\n    label: AC\n    weight: 60\n    blocking: true\n  - id: data\n    label: Data\n    weight: 40\nthresholds:\n  go: 100\n",
+        );
+        assert_eq!(checklist.thresholds.clarify, 50);
+        assert_eq!(checklist.decision(&["ac", "data"]), Decision::Go);
+        assert_eq!(checklist.decision(&["ac"]), Decision::Clarify);
+        assert_eq!(checklist.score(&["data"]), 40);
+        assert_eq!(checklist.decision(&["data"]), Decision::NoGo);
+        assert_eq!(Decision::NoGo.to_string(), "No-Go");
+    }
+
+    #[test]
+    fn test_validation_reports_ids_weights_and_thresholds() {
+        let invalid = checklist(
+            "criteria:\n  - id: ac\n    label: AC\n    weight: 50\n  - id: ac\n    label: \"\"\n    weight: 30\nthresholds:\n  go: 60\n  clarify: 70\n",
+        );
+        assert_eq!(
+            fields(&invalid),
+            [
+                "checklist.criteria.1.id",
+                "checklist.criteria.1.label",
+                "checklist.criteria",
+                "checklist.thresholds.clarify",
+            ]
+        );
+
+        let empty = checklist("criteria: []\n");
+        assert_eq!(fields(&empty), ["checklist.criteria"]);
+    }
+}
@@ -1,38 +1,79 @@
 //! Configuration structures and loading logic
 
+use crate::anonymization::{validate_anonymization, validate_strict_mode, AnonymizationConfig};
+use crate::checklist::{validate_checklist, ChecklistConfig, ChecklistCriterion};
 use crate::error::ConfigError;
-use crate::profiles::ProfileOverride;
-use serde::Deserialize;
-use std::collections::HashMap;
+use crate::format::{ConfigFormat, FormatError};
+use crate::include::resolve_includes;
+use crate::interpolate::interpolate_env_vars;
+use crate::location::ConfigSource;
+use crate::migrate::{migrate_for_load, CURRENT_CONFIG_VERSION};
+use crate::network::{validate_client_certificates, validate_network, Integration, NetworkConfig};
+use crate::profiles::{MergeStrategy, ProfileOverride};
+use crate::provenance::{Provenance, ValueSource};
+use crate::resilience::{validate_resilience, ResilienceConfig};
+use crate::schema::enum_definition;
+use crate::scopes::{validate_jira_scopes, JiraScope};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Main project configuration
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ProjectConfig {
+    /// Schema version of the file (see [`crate::migrate`]).
+    ///
+    /// Files without it are version 0. [`load_config`] migrates older documents
+    /// in memory, so after loading this is always [`CURRENT_CONFIG_VERSION`].
+    #[serde(default = "current_config_version")]
+    pub config_version: u32,
+
+    /// Fragment files merged into this configuration before profiles are
+    /// resolved, relative to this file (see [`crate::include`]).
+    ///
+    /// Not written back by [`ProjectConfig::to_yaml_redacted`], whose output
+    /// already contains the merged values.
+    #[serde(default, skip_serializing)]
+    pub include: Option<Vec<String>>,
+
     /// Name of the project
     pub project_name: String,
 
     /// Jira integration configuration
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub jira: Option<JiraConfig>,
 
     /// Squash integration configuration
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub squash: Option<SquashConfig>,
 
     /// Output folder for generated files
     pub output_folder: String,
 
     /// Template file paths
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub templates: Option<TemplatesConfig>,
 
     /// LLM configuration
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub llm: Option<LlmConfig>,
 
+    /// Proxy, CA bundle and TLS settings shared by the integrations (see [`crate::network`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Testability checklist and Go/Clarify/No-Go thresholds (see [`crate::checklist`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<ChecklistConfig>,
+
+    /// Anonymization policy for data sent to LLMs (see [`crate::anonymization`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anonymization: Option<AnonymizationConfig>,
+
     /// Configuration profiles for environment-specific overrides.
     ///
     /// Profiles allow switching between different configurations (dev, staging, prod)
@@ -42,6 +83,7 @@ pub struct ProjectConfig {
     /// - `squash`: Squash integration settings
     /// - `llm`: LLM provider settings
     /// - `templates`: Template file paths
+    /// - `checklist`: Testability checklist
     ///
     /// # Example
     ///
@@ -54,50 +96,109 @@ pub struct ProjectConfig {
     ///   prod:
     ///     # Empty profile uses base values
     /// ```
-    #[serde(default)]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::export::serialize_profiles"
+    )]
     pub profiles: Option<HashMap<String, ProfileOverride>>,
 
+    /// Profile applied by [`load_config_with_profile`] when neither the caller
+    /// nor the `TF_PROFILE` environment variable names one.
+    ///
+    /// Must name a profile defined under `profiles`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+
     /// Currently active profile name (set after calling `with_profile()`).
     ///
     /// This field is `None` when no profile has been applied.
     /// After calling `config.with_profile("dev")`, this will be `Some("dev")`.
     #[serde(skip)]
     pub active_profile: Option<String>,
+
+    /// Key positions of the file this configuration was loaded from.
+    ///
+    /// Set by [`load_config`] and used to attach line/column information to
+    /// errors, including errors raised later by `with_profile()`. `None` for
+    /// configurations built in code.
+    #[serde(skip)]
+    pub source: Option<Arc<ConfigSource>>,
+
+    /// Directory containing the configuration file.
+    ///
+    /// Set by [`load_config`]. Relative paths in the configuration (`output_folder`,
+    /// template paths) are resolved against it by [`ProjectConfig::resolve_path`].
+    /// `None` for configurations built in code, in which case relative paths are
+    /// resolved against the current working directory.
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
+
+    /// Where each effective value came from (file, default, profile, env, override).
+    ///
+    /// Filled by [`load_config`] and updated by `with_profile()` and the override
+    /// methods. Empty for configurations built in code.
+    #[serde(skip)]
+    pub provenance: Provenance,
 }
 
 /// Jira integration configuration
-#[derive(Clone, PartialEq, Deserialize)]
+#[derive(Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct JiraConfig {
     /// Jira server endpoint URL
     pub endpoint: String,
 
     /// API token (sensitive - will be redacted in logs via custom Debug impl)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub token: Option<String>,
+
+    /// Network settings overriding the shared `network` section for Jira
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Retry and fallback policy for Jira calls (see [`crate::resilience`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resilience: Option<ResilienceConfig>,
+
+    /// Named import scopes, selected with [`JiraConfig::scope`] (see [`crate::scopes`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<BTreeMap<String, JiraScope>>,
+
+    /// Jira field ids of logical fields (e.g. `acceptance_criteria: customfield_10042`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, String>>,
 }
 
 /// Squash integration configuration
-#[derive(Clone, PartialEq, Deserialize)]
+#[derive(Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SquashConfig {
     /// Squash server endpoint URL
     pub endpoint: String,
 
     /// Username for authentication
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 
     /// Password (sensitive - will be redacted in logs via custom Debug impl)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+
+    /// Network settings overriding the shared `network` section for Squash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Retry and fallback policy for Squash calls (see [`crate::resilience`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resilience: Option<ResilienceConfig>,
 }
 
 /// Template file paths configuration for document generation.
 ///
 /// All template paths are optional. When provided, they should point to
 /// valid template files that will be used for generating reports and documents.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TemplatesConfig {
     /// Path to CR (compte-rendu/daily report) template file.
@@ -105,7 +206,7 @@ pub struct TemplatesConfig {
     /// Used for generating daily status reports. Typically a Markdown file
     /// with placeholders for test execution data.
     /// Example: `"./templates/cr.md"`
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cr: Option<String>,
 
     /// Path to PPT (PowerPoint presentation) template file.
@@ -113,7 +214,7 @@ pub struct TemplatesConfig {
     /// Used for generating weekly status presentations and TNR reports.
     /// Should be a `.pptx` file with placeholder slides.
     /// Example: `"./templates/report.pptx"`
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ppt: Option<String>,
 
     /// Path to anomaly report template file.
@@ -122,12 +223,12 @@ pub struct TemplatesConfig {
     /// Typically a Markdown file with sections for reproduction steps,
     /// expected vs actual behavior, and evidence links.
     /// Example: `"./templates/anomaly.md"`
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub anomaly: Option<String>,
 }
 
 /// LLM operation mode
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum LlmMode {
     /// Automatically select local or cloud based on availability
@@ -150,7 +251,7 @@ impl std::fmt::Display for LlmMode {
 }
 
 /// LLM (Large Language Model) configuration
-#[derive(Clone, PartialEq, Deserialize)]
+#[derive(Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LlmConfig {
     /// LLM mode: auto, local, or cloud
@@ -158,11 +259,11 @@ pub struct LlmConfig {
     pub mode: LlmMode,
 
     /// Local LLM endpoint (e.g., Ollama at `http://localhost:11434`)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub local_endpoint: Option<String>,
 
     /// Local model name (e.g., `mistral:7b-instruct`, `codellama:13b`)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub local_model: Option<String>,
 
     /// Whether cloud LLM is enabled (required for cloud/auto mode to use cloud)
@@ -170,15 +271,15 @@ pub struct LlmConfig {
     pub cloud_enabled: bool,
 
     /// Cloud LLM endpoint (e.g., `https://api.openai.com/v1`)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cloud_endpoint: Option<String>,
 
     /// Cloud model name (e.g., `gpt-4o-mini`)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cloud_model: Option<String>,
 
     /// API key for cloud LLM (sensitive - will be redacted in logs via custom Debug impl)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub api_key: Option<String>,
 
     /// Request timeout in seconds (default: 120)
@@ -188,13 +289,25 @@ pub struct LlmConfig {
     /// Maximum tokens for LLM response (default: 4096)
     #[serde(default = "default_max_tokens")]
     pub max_tokens: u32,
+
+    /// Network settings overriding the shared `network` section for the LLM
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Retry and fallback policy for LLM calls (see [`crate::resilience`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resilience: Option<ResilienceConfig>,
+}
+
+fn current_config_version() -> u32 {
+    CURRENT_CONFIG_VERSION
 }
 
-fn default_timeout_seconds() -> u32 {
+pub(crate) fn default_timeout_seconds() -> u32 {
     120
 }
 
-fn default_max_tokens() -> u32 {
+pub(crate) fn default_max_tokens() -> u32 {
     4096
 }
 
@@ -595,10 +708,12 @@ impl ProjectConfig {
     ///
     /// Returns `None` if the path exists and is a directory.
     ///
+    /// A relative `output_folder` is checked relative to [`ProjectConfig::base_dir`].
+    ///
     /// This is an optional validation - the caller decides whether to
     /// warn the user, create the folder, or treat it as an error.
     pub fn check_output_folder_exists(&self) -> Option<String> {
-        let path = Path::new(&self.output_folder);
+        let path = self.output_folder_path();
         if !path.exists() {
             Some(format!(
                 "output_folder '{}' does not exist - it will be created when needed",
@@ -614,6 +729,36 @@ impl ProjectConfig {
         }
     }
 
+    /// Resolve a path from the configuration against the configuration file directory.
+    ///
+    /// Absolute paths are returned unchanged. Relative paths are joined to
+    /// [`ProjectConfig::base_dir`] when it is set, and returned as-is (relative to
+    /// the current working directory) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use tf_config::ProjectConfig;
+    ///
+    /// let mut config: ProjectConfig =
+    ///     serde_yaml::from_str("project_name: \"demo\"\noutput_folder: \"./output\"\n").unwrap();
+    /// config.base_dir = Some(PathBuf::from("/srv/project"));
+    /// assert_eq!(config.output_folder_path(), Path::new("/srv/project/output"));
+    /// ```
+    pub fn resolve_path(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        match &self.base_dir {
+            Some(base_dir) if path.is_relative() => base_dir.join(path),
+            _ => path.to_path_buf(),
+        }
+    }
+
+    /// The `output_folder` resolved with [`ProjectConfig::resolve_path`].
+    pub fn output_folder_path(&self) -> PathBuf {
+        self.resolve_path(&self.output_folder)
+    }
+
     /// Apply a named profile to the configuration, returning a new merged configuration.
     ///
     /// This method looks up the specified profile in the `profiles` map and merges
@@ -657,8 +802,21 @@ impl ProjectConfig {
                 available: available_profiles,
             })?;
 
-        // Apply the profile and set active_profile
-        let mut merged = self.apply_profile(profile);
+        // Resolve the `extends` chain, outermost ancestor first
+        let chain = if profile.extends.is_some() {
+            self.profile_chain(profile_name)
+                .map_err(|e| self.locate_error(e))?
+        } else {
+            vec![profile_name.to_string()]
+        };
+
+        // Apply each profile of the chain in turn and set active_profile
+        let mut merged = self.clone();
+        for name in &chain {
+            if let Some(p) = self.profiles.as_ref().and_then(|p| p.get(name)) {
+                merged = merged.apply_named_profile(p, name);
+            }
+        }
         merged.active_profile = Some(profile_name.to_string());
 
         // Validate the merged configuration to ensure profile overrides don't break invariants
@@ -666,16 +824,72 @@ impl ProjectConfig {
         // - Profile setting output_folder with path traversal (..)
         // - Profile setting invalid LLM configuration (e.g., mode: cloud without api_key)
         // - Profile setting empty or invalid URLs
-        validate_config(&merged)?;
+        validate_config(&merged).map_err(|e| merged.locate_error(e))?;
 
         Ok(merged)
     }
 
+    /// Resolve the inheritance chain of a profile through its `extends` keys.
+    ///
+    /// Returns the profile names in application order: the outermost ancestor
+    /// first and `profile_name` last. A profile without `extends` yields a
+    /// single-element chain.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConfigError::ProfileNotFound`] if `profile_name` is not defined
+    /// - [`ConfigError::InvalidValue`] if an `extends` key names an unknown profile
+    /// - [`ConfigError::ProfileCycle`] if the chain loops back on itself
+    pub fn profile_chain(&self, profile_name: &str) -> Result<Vec<String>, ConfigError> {
+        let mut available: Vec<String> = self
+            .profiles
+            .as_ref()
+            .map(|p| p.keys().cloned().collect())
+            .unwrap_or_default();
+        available.sort();
+
+        let mut chain: Vec<String> = Vec::new();
+        let mut current = profile_name.to_string();
+        loop {
+            if let Some(pos) = chain.iter().position(|name| *name == current) {
+                let mut cycle = chain[pos..].to_vec();
+                cycle.push(current);
+                return Err(ConfigError::ProfileCycle { chain: cycle });
+            }
+
+            let Some(profile) = self.profiles.as_ref().and_then(|p| p.get(&current)) else {
+                return Err(match chain.last() {
+                    None => ConfigError::ProfileNotFound {
+                        requested: current,
+                        available,
+                    },
+                    Some(child) => ConfigError::invalid_value(
+                        format!("profiles.{}.extends", child),
+                        format!("references unknown profile '{}'", current),
+                        format!("the name of a defined profile ({})", available.join(", ")),
+                    ),
+                });
+            };
+
+            let parent = profile.extends.clone();
+            chain.push(current);
+            match parent {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        chain.reverse();
+        Ok(chain)
+    }
+
     /// Apply a ProfileOverride to the configuration, returning a new merged configuration.
     ///
     /// This is the low-level merge operation. Fields specified in the override replace
     /// the corresponding fields in the base configuration. Fields set to `None` in the
     /// override preserve the base configuration value (partial override pattern).
+    /// Sections are replaced as a whole or deep-merged field by field according to
+    /// their [`MergeStrategy`].
     ///
     /// Note: This method does NOT set `active_profile`. Use `with_profile()` for that.
     ///
@@ -690,17 +904,26 @@ impl ProjectConfig {
     /// # Example
     ///
     /// ```
-    /// use tf_config::{ProfileOverride, ProjectConfig};
+    /// use tf_config::{ProfileOverride, ProjectConfig, CURRENT_CONFIG_VERSION};
     ///
     /// let base_config = ProjectConfig {
+    ///     config_version: CURRENT_CONFIG_VERSION,
+    ///     include: None,
     ///     project_name: "my-project".to_string(),
     ///     output_folder: "./output".to_string(),
     ///     jira: None,
     ///     squash: None,
     ///     llm: None,
     ///     templates: None,
+    ///     checklist: None,
+    ///     anonymization: None,
+    ///     network: None,
     ///     profiles: None,
+    ///     default_profile: None,
     ///     active_profile: None,
+    ///     source: None,
+    ///     base_dir: None,
+    ///     provenance: Default::default(),
     /// };
     /// let profile = ProfileOverride {
     ///     output_folder: Some("./dev-output".to_string()),
@@ -710,8 +933,19 @@ impl ProjectConfig {
     /// assert_eq!(merged.output_folder, "./dev-output");
     /// ```
     pub fn apply_profile(&self, profile: &ProfileOverride) -> ProjectConfig {
+        self.apply_named_profile(profile, "")
+    }
+
+    /// [`ProjectConfig::apply_profile`], recording `name` as the source of the
+    /// values the profile sets.
+    fn apply_named_profile(&self, profile: &ProfileOverride, name: &str) -> ProjectConfig {
+        let strategy = profile.merge.unwrap_or_default();
+        let mut provenance = self.provenance.clone();
+        provenance.record_profile(self, profile, name);
         ProjectConfig {
             // project_name is never overridden (not part of ProfileOverride)
+            config_version: self.config_version,
+            include: self.include.clone(),
             project_name: self.project_name.clone(),
 
             // Override output_folder if specified, otherwise keep base
@@ -720,23 +954,44 @@ impl ProjectConfig {
                 .clone()
                 .unwrap_or_else(|| self.output_folder.clone()),
 
-            // Override jira if specified, otherwise keep base
-            jira: profile.jira.clone().or_else(|| self.jira.clone()),
-
-            // Override squash if specified, otherwise keep base
-            squash: profile.squash.clone().or_else(|| self.squash.clone()),
-
-            // Override llm if specified, otherwise keep base
-            llm: profile.llm.clone().or_else(|| self.llm.clone()),
-
-            // Override templates if specified, otherwise keep base
-            templates: profile.templates.clone().or_else(|| self.templates.clone()),
+            // Replace or deep-merge each section if specified, otherwise keep base
+            jira: match &profile.jira {
+                Some(jira) => Some(jira.merge_onto(self.jira.as_ref(), strategy)),
+                None => self.jira.clone(),
+            },
+            squash: match &profile.squash {
+                Some(squash) => Some(squash.merge_onto(self.squash.as_ref(), strategy)),
+                None => self.squash.clone(),
+            },
+            llm: match &profile.llm {
+                Some(llm) => Some(llm.merge_onto(self.llm.as_ref(), strategy)),
+                None => self.llm.clone(),
+            },
+            templates: match &profile.templates {
+                Some(templates) => Some(templates.merge_onto(self.templates.as_ref(), strategy)),
+                None => self.templates.clone(),
+            },
+            checklist: match &profile.checklist {
+                Some(checklist) => Some(checklist.merge_onto(self.checklist.as_ref(), strategy)),
+                None => self.checklist.clone(),
+            },
+            network: self.network.clone(),
+            anonymization: self.anonymization.clone(),
 
             // Preserve profiles map (allow chaining)
             profiles: self.profiles.clone(),
 
             // active_profile is NOT set here - use with_profile() for that
+            default_profile: self.default_profile.clone(),
             active_profile: self.active_profile.clone(),
+
+            // Keep the source so errors on the merged config can be located
+            source: self.source.clone(),
+
+            // Profiles come from the same file, so paths resolve the same way
+            base_dir: self.base_dir.clone(),
+
+            provenance,
         }
     }
 
@@ -750,23 +1005,128 @@ impl ProjectConfig {
     ///
     /// A multi-line string summarizing the current configuration state.
     pub fn active_profile_summary(&self) -> String {
+        self.summary(false)
+    }
+
+    /// Same as [`ProjectConfig::active_profile_summary`], with the source of each
+    /// value (see [`ProjectConfig::provenance`]), e.g. `LLM: local (from profile 'dev')`.
+    pub fn active_profile_summary_with_sources(&self) -> String {
+        self.summary(true)
+    }
+
+    fn summary(&self, with_sources: bool) -> String {
+        // " (from <source>)" suffix for a field, when requested and known
+        let from = |field: &str| match self.provenance.get(field) {
+            Some(source) if with_sources => format!(" (from {})", source),
+            _ => String::new(),
+        };
+
+        // " (from <sources>)" suffix for a block, listing the sources of its leaves
+        let from_block = |path: &str| {
+            if !with_sources {
+                return String::new();
+            }
+            let nested = format!("{}.", path);
+            let mut sources: Vec<String> = Vec::new();
+            for (field, source) in self.provenance.iter() {
+                let source = source.to_string();
+                if (field == path || field.starts_with(&nested)) && !sources.contains(&source) {
+                    sources.push(source);
+                }
+            }
+            if sources.is_empty() {
+                String::new()
+            } else {
+                format!(" (from {})", sources.join(", "))
+            }
+        };
+        // Set keys of the serialized `section` at `path`, each with its sources
+        let keys = |path: &str, section: serde_yaml::Value| -> Vec<String> {
+            section
+                .as_mapping()
+                .into_iter()
+                .flatten()
+                .filter(|(_, value)| !value.is_null())
+                .filter_map(|(key, _)| key.as_str())
+                .map(|key| format!("{}{}", key, from_block(&format!("{}.{}", path, key))))
+                .collect()
+        };
+        let with_blocks = |line: String, path: &str, blocks: &[(&str, bool)]| {
+            let set: Vec<String> = blocks
+                .iter()
+                .filter(|(_, is_set)| *is_set)
+                .map(|(name, _)| format!("{}{}", name, from_block(&format!("{}.{}", path, name))))
+                .collect();
+            if set.is_empty() {
+                line
+            } else {
+                format!("{}; {}", line, set.join(", "))
+            }
+        };
+
+        // How load_config_with_profile selected the profile, when it did
+        let selected_by = match self.provenance.get("active_profile") {
+            Some(ValueSource::Override) => ", selected explicitly".to_string(),
+            Some(ValueSource::Env(variable)) => format!(", selected by {}", variable),
+            Some(ValueSource::File) => ", selected by default_profile".to_string(),
+            _ => String::new(),
+        };
         let profile_status = match &self.active_profile {
-            Some(name) => format!("Active profile: {}", name),
+            Some(name) => match self.profile_chain(name) {
+                Ok(chain) if chain.len() > 1 => format!(
+                    "Active profile: {} (base -> {}){}",
+                    name,
+                    chain.join(" -> "),
+                    selected_by
+                ),
+                _ => format!("Active profile: {}{}", name, selected_by),
+            },
             None => "No profile active (using base configuration)".to_string(),
         };
 
         let jira_status = match &self.jira {
-            Some(j) => format!("Jira: {}", redact_url_sensitive_params(&j.endpoint)),
+            Some(j) => with_blocks(
+                format!(
+                    "Jira: {}{}",
+                    redact_url_sensitive_params(&j.endpoint),
+                    from("jira.endpoint")
+                ),
+                "jira",
+                &[
+                    ("network", j.network.is_some()),
+                    ("resilience", j.resilience.is_some()),
+                    ("scopes", j.scopes.is_some()),
+                    ("fields", j.fields.is_some()),
+                ],
+            ),
             None => "Jira: not configured".to_string(),
         };
 
         let squash_status = match &self.squash {
-            Some(s) => format!("Squash: {}", redact_url_sensitive_params(&s.endpoint)),
+            Some(s) => with_blocks(
+                format!(
+                    "Squash: {}{}",
+                    redact_url_sensitive_params(&s.endpoint),
+                    from("squash.endpoint")
+                ),
+                "squash",
+                &[
+                    ("network", s.network.is_some()),
+                    ("resilience", s.resilience.is_some()),
+                ],
+            ),
             None => "Squash: not configured".to_string(),
         };
 
         let llm_status = match &self.llm {
-            Some(l) => format!("LLM: {}", l.mode),
+            Some(l) => with_blocks(
+                format!("LLM: {}{}", l.mode, from("llm.mode")),
+                "llm",
+                &[
+                    ("network", l.network.is_some()),
+                    ("resilience", l.resilience.is_some()),
+                ],
+            ),
             None => "LLM: not configured".to_string(),
         };
 
@@ -779,7 +1139,7 @@ impl ProjectConfig {
                 ]
                 .iter()
                 .filter_map(|&p| p)
-                .map(|s| s.to_string())
+                .map(|s| format!("{}{}", s, from(&format!("templates.{}", s))))
                 .collect();
                 if parts.is_empty() {
                     "Templates: configured (none set)".to_string()
@@ -790,16 +1150,313 @@ impl ProjectConfig {
             None => "Templates: not configured".to_string(),
         };
 
-        format!(
-            "{}\nOutput folder: {}\n{}\n{}\n{}\n{}",
+        let mut summary = format!(
+            "{}\nOutput folder: {}{}\n{}\n{}\n{}\n{}",
             profile_status,
             self.output_folder,
+            from("output_folder"),
             jira_status,
             squash_status,
             llm_status,
             templates_status
-        )
+        );
+        // Sections without a one-line value list their set keys, when configured
+        let sections = [
+            ("Network", "network", serialized(&self.network)),
+            ("Checklist", "checklist", serialized(&self.checklist)),
+            (
+                "Anonymization",
+                "anonymization",
+                serialized(&self.anonymization),
+            ),
+        ];
+        for (title, path, section) in sections {
+            if !section.is_null() {
+                summary.push_str(&format!("\n{}: {}", title, keys(path, section).join(", ")));
+            }
+        }
+        summary
+    }
+
+    /// Attach the file location of the offending key to `err`.
+    ///
+    /// When a profile is active, the profile override key is preferred over the
+    /// base key. Errors are returned unchanged for configurations built in code.
+    pub(crate) fn locate_error(&self, err: ConfigError) -> ConfigError {
+        let Some(source) = &self.source else {
+            return err;
+        };
+        // Innermost profile first: its keys win over those it inherits
+        let chain = match &self.active_profile {
+            Some(name) => self
+                .profile_chain(name)
+                .unwrap_or_else(|_| vec![name.clone()]),
+            None => Vec::new(),
+        };
+        let candidates: Vec<String> = chain
+            .iter()
+            .rev()
+            .map(|name| format!("profiles.{}.", name))
+            .collect();
+        source.attach(err, &candidates)
+    }
+
+    /// Run every validation check and return all problems found.
+    ///
+    /// Returns an empty list when the configuration is valid. This is useful to
+    /// report a full checklist for a configuration built or merged in code
+    /// (e.g. after [`ProjectConfig::apply_profile`]).
+    pub fn validation_errors(&self) -> Vec<ConfigError> {
+        collect_validation_errors(self)
+    }
+
+    /// Visit every string value of the configuration, including profile overrides.
+    ///
+    /// The visitor receives the dotted field path (e.g. `jira.token` or
+    /// `profiles.dev.llm.api_key`) and a mutable reference to the value, so that
+    /// resolution passes (secret references, interpolation) can rewrite values in
+    /// place. Profiles are visited in sorted order so that the first reported error
+    /// is deterministic. Visiting stops at the first error returned by the visitor.
+    pub(crate) fn try_for_each_string_mut(
+        &mut self,
+        visitor: &mut StringVisitor<'_>,
+    ) -> Result<(), ConfigError> {
+        visitor("project_name", &mut self.project_name)?;
+        visitor("output_folder", &mut self.output_folder)?;
+        visit_sections_mut(
+            "",
+            &mut self.jira,
+            &mut self.squash,
+            &mut self.llm,
+            &mut self.templates,
+            visitor,
+        )?;
+        visit_network_mut("", &mut self.network, visitor)?;
+        if let Some(checklist) = &mut self.checklist {
+            visit_criteria_mut("", &mut checklist.criteria, visitor)?;
+        }
+        visit_anonymization_mut(&mut self.anonymization, visitor)?;
+
+        if let Some(profiles) = self.profiles.as_mut() {
+            let mut names: Vec<String> = profiles.keys().cloned().collect();
+            names.sort();
+            for name in names {
+                let Some(profile) = profiles.get_mut(&name) else {
+                    continue;
+                };
+                let prefix = format!("profiles.{}.", name);
+                visit_opt_string_mut(
+                    &prefix,
+                    "output_folder",
+                    &mut profile.output_folder,
+                    visitor,
+                )?;
+                visit_override_sections_mut(&prefix, profile, visitor)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Serialized form of an optional section (`Null` when unset).
+fn serialized<T: Serialize>(section: &Option<T>) -> serde_yaml::Value {
+    serde_yaml::to_value(section).expect("configuration sections serialize to YAML")
+}
+
+/// Callback used by [`ProjectConfig::try_for_each_string_mut`].
+pub(crate) type StringVisitor<'a> = dyn FnMut(&str, &mut String) -> Result<(), ConfigError> + 'a;
+
+/// Visit an optional string field, skipping it when unset.
+fn visit_opt_string_mut(
+    prefix: &str,
+    field: &str,
+    value: &mut Option<String>,
+    visitor: &mut StringVisitor<'_>,
+) -> Result<(), ConfigError> {
+    match value {
+        Some(v) => visitor(&format!("{}{}", prefix, field), v),
+        None => Ok(()),
+    }
+}
+
+/// Visit the string fields of a `network` block, named `<prefix>network.<field>`.
+fn visit_network_mut(
+    prefix: &str,
+    network: &mut Option<NetworkConfig>,
+    visitor: &mut StringVisitor<'_>,
+) -> Result<(), ConfigError> {
+    let Some(network) = network else {
+        return Ok(());
+    };
+    let prefix = format!("{}network.", prefix);
+    visit_opt_string_mut(&prefix, "proxy", &mut network.proxy, visitor)?;
+    for (index, entry) in network.no_proxy.iter_mut().flatten().enumerate() {
+        visitor(&format!("{}no_proxy.{}", prefix, index), entry)?;
+    }
+    visit_opt_string_mut(&prefix, "ca_bundle", &mut network.ca_bundle, visitor)?;
+    visit_opt_string_mut(&prefix, "client_cert", &mut network.client_cert, visitor)?;
+    visit_opt_string_mut(&prefix, "client_key", &mut network.client_key, visitor)
+}
+
+/// Visit the string values of `jira.scopes` and `jira.fields`, named
+/// `<prefix>jira.scopes.<name>.<field>` and `<prefix>jira.fields.<name>`.
+fn visit_jira_scopes_mut(
+    prefix: &str,
+    scopes: &mut Option<BTreeMap<String, JiraScope>>,
+    fields: &mut Option<BTreeMap<String, String>>,
+    visitor: &mut StringVisitor<'_>,
+) -> Result<(), ConfigError> {
+    for (name, scope) in scopes.iter_mut().flatten() {
+        let scope_prefix = format!("{}jira.scopes.{}.", prefix, name);
+        for (list, values) in [
+            ("projects", &mut scope.projects),
+            ("components", &mut scope.components),
+            ("fix_versions", &mut scope.fix_versions),
+        ] {
+            for (index, value) in values.iter_mut().enumerate() {
+                visitor(&format!("{}{}.{}", scope_prefix, list, index), value)?;
+            }
+        }
+        visit_opt_string_mut(&scope_prefix, "jql", &mut scope.jql, visitor)?;
+        visit_opt_string_mut(&scope_prefix, "window", &mut scope.window, visitor)?;
+    }
+    for (name, id) in fields.iter_mut().flatten() {
+        visitor(&format!("{}jira.fields.{}", prefix, name), id)?;
+    }
+    Ok(())
+}
+
+/// Visit the string fields of the integration sections shared by the base
+/// configuration and profile overrides.
+fn visit_sections_mut(
+    prefix: &str,
+    jira: &mut Option<JiraConfig>,
+    squash: &mut Option<SquashConfig>,
+    llm: &mut Option<LlmConfig>,
+    templates: &mut Option<TemplatesConfig>,
+    visitor: &mut StringVisitor<'_>,
+) -> Result<(), ConfigError> {
+    if let Some(jira) = jira {
+        visitor(&format!("{}jira.endpoint", prefix), &mut jira.endpoint)?;
+        visit_opt_string_mut(prefix, "jira.token", &mut jira.token, visitor)?;
+        visit_network_mut(&format!("{}jira.", prefix), &mut jira.network, visitor)?;
+        visit_jira_scopes_mut(prefix, &mut jira.scopes, &mut jira.fields, visitor)?;
+    }
+    if let Some(squash) = squash {
+        visitor(&format!("{}squash.endpoint", prefix), &mut squash.endpoint)?;
+        visit_opt_string_mut(prefix, "squash.username", &mut squash.username, visitor)?;
+        visit_opt_string_mut(prefix, "squash.password", &mut squash.password, visitor)?;
+        visit_network_mut(&format!("{}squash.", prefix), &mut squash.network, visitor)?;
+    }
+    if let Some(llm) = llm {
+        visit_opt_string_mut(
+            prefix,
+            "llm.local_endpoint",
+            &mut llm.local_endpoint,
+            visitor,
+        )?;
+        visit_opt_string_mut(prefix, "llm.local_model", &mut llm.local_model, visitor)?;
+        visit_opt_string_mut(
+            prefix,
+            "llm.cloud_endpoint",
+            &mut llm.cloud_endpoint,
+            visitor,
+        )?;
+        visit_opt_string_mut(prefix, "llm.cloud_model", &mut llm.cloud_model, visitor)?;
+        visit_opt_string_mut(prefix, "llm.api_key", &mut llm.api_key, visitor)?;
+        visit_network_mut(&format!("{}llm.", prefix), &mut llm.network, visitor)?;
+    }
+    if let Some(templates) = templates {
+        visit_opt_string_mut(prefix, "templates.cr", &mut templates.cr, visitor)?;
+        visit_opt_string_mut(prefix, "templates.ppt", &mut templates.ppt, visitor)?;
+        visit_opt_string_mut(prefix, "templates.anomaly", &mut templates.anomaly, visitor)?;
+    }
+    Ok(())
+}
+
+/// Visit the string fields of the partial sections of a profile override.
+fn visit_override_sections_mut(
+    prefix: &str,
+    profile: &mut ProfileOverride,
+    visitor: &mut StringVisitor<'_>,
+) -> Result<(), ConfigError> {
+    if let Some(jira) = &mut profile.jira {
+        visit_opt_string_mut(prefix, "jira.endpoint", &mut jira.endpoint, visitor)?;
+        visit_opt_string_mut(prefix, "jira.token", &mut jira.token, visitor)?;
+        visit_network_mut(&format!("{}jira.", prefix), &mut jira.network, visitor)?;
+        visit_jira_scopes_mut(prefix, &mut jira.scopes, &mut jira.fields, visitor)?;
+    }
+    if let Some(squash) = &mut profile.squash {
+        visit_opt_string_mut(prefix, "squash.endpoint", &mut squash.endpoint, visitor)?;
+        visit_opt_string_mut(prefix, "squash.username", &mut squash.username, visitor)?;
+        visit_opt_string_mut(prefix, "squash.password", &mut squash.password, visitor)?;
+        visit_network_mut(&format!("{}squash.", prefix), &mut squash.network, visitor)?;
+    }
+    if let Some(llm) = &mut profile.llm {
+        visit_opt_string_mut(
+            prefix,
+            "llm.local_endpoint",
+            &mut llm.local_endpoint,
+            visitor,
+        )?;
+        visit_opt_string_mut(prefix, "llm.local_model", &mut llm.local_model, visitor)?;
+        visit_opt_string_mut(
+            prefix,
+            "llm.cloud_endpoint",
+            &mut llm.cloud_endpoint,
+            visitor,
+        )?;
+        visit_opt_string_mut(prefix, "llm.cloud_model", &mut llm.cloud_model, visitor)?;
+        visit_opt_string_mut(prefix, "llm.api_key", &mut llm.api_key, visitor)?;
+        visit_network_mut(&format!("{}llm.", prefix), &mut llm.network, visitor)?;
+    }
+    if let Some(templates) = &mut profile.templates {
+        visit_opt_string_mut(prefix, "templates.cr", &mut templates.cr, visitor)?;
+        visit_opt_string_mut(prefix, "templates.ppt", &mut templates.ppt, visitor)?;
+        visit_opt_string_mut(prefix, "templates.anomaly", &mut templates.anomaly, visitor)?;
+    }
+    if let Some(criteria) = profile.checklist.as_mut().and_then(|c| c.criteria.as_mut()) {
+        visit_criteria_mut(prefix, criteria, visitor)?;
+    }
+    Ok(())
+}
+
+/// Visit the ids and labels of checklist criteria, named
+/// `<prefix>checklist.criteria.<index>.<field>`.
+fn visit_criteria_mut(
+    prefix: &str,
+    criteria: &mut [ChecklistCriterion],
+    visitor: &mut StringVisitor<'_>,
+) -> Result<(), ConfigError> {
+    for (index, criterion) in criteria.iter_mut().enumerate() {
+        let field = |name: &str| format!("{}checklist.criteria.{}.{}", prefix, index, name);
+        visitor(&field("id"), &mut criterion.id)?;
+        visitor(&field("label"), &mut criterion.label)?;
+    }
+    Ok(())
+}
+
+/// Visit the rule names and the allow-list values of the `anonymization`
+/// section. Rule patterns and replacements are skipped: `$` is a regex anchor
+/// and `${1}` a capture group reference.
+fn visit_anonymization_mut(
+    anonymization: &mut Option<AnonymizationConfig>,
+    visitor: &mut StringVisitor<'_>,
+) -> Result<(), ConfigError> {
+    let Some(anonymization) = anonymization else {
+        return Ok(());
+    };
+    for (index, rule) in anonymization.rules.iter_mut().enumerate() {
+        let prefix = format!("anonymization.rules.{}.", index);
+        visitor(&format!("{}name", prefix), &mut rule.name)?;
     }
+    for (name, values) in &mut anonymization.allow {
+        for (index, value) in values.iter_mut().enumerate() {
+            visitor(&format!("anonymization.allow.{}.{}", name, index), value)?;
+        }
+    }
+    Ok(())
 }
 
 impl fmt::Debug for JiraConfig {
@@ -807,6 +1464,10 @@ impl fmt::Debug for JiraConfig {
         f.debug_struct("JiraConfig")
             .field("endpoint", &redact_url_sensitive_params(&self.endpoint))
             .field("token", &"[REDACTED]")
+            .field("network", &self.network)
+            .field("resilience", &self.resilience)
+            .field("scopes", &self.scopes)
+            .field("fields", &self.fields)
             .finish()
     }
 }
@@ -817,6 +1478,8 @@ impl fmt::Debug for SquashConfig {
             .field("endpoint", &redact_url_sensitive_params(&self.endpoint))
             .field("username", &self.username)
             .field("password", &"[REDACTED]")
+            .field("network", &self.network)
+            .field("resilience", &self.resilience)
             .finish()
     }
 }
@@ -842,6 +1505,8 @@ impl fmt::Debug for LlmConfig {
             .field("api_key", &"[REDACTED]")
             .field("timeout_seconds", &self.timeout_seconds)
             .field("max_tokens", &self.max_tokens)
+            .field("network", &self.network)
+            .field("resilience", &self.resilience)
             .finish()
     }
 }
@@ -871,6 +1536,141 @@ impl fmt::Debug for LlmConfig {
 ///
 /// For a working test example, see `test_load_valid_config` in the test module.
 pub fn load_config(path: &Path) -> Result<ProjectConfig, ConfigError> {
+    let config = parse_config_file(path)?;
+
+    // Validate required fields
+    validate_config(&config).map_err(|e| config.locate_error(e))?;
+
+    Ok(config)
+}
+
+/// Environment variable naming the profile to apply (see [`load_config_with_profile`]).
+pub const PROFILE_ENV_VAR: &str = "TF_PROFILE";
+
+/// Load and validate a configuration file, apply the selected profile, then the
+/// `TF__*` environment overrides.
+///
+/// The profile is chosen by precedence:
+///
+/// 1. `profile`, when the caller names one (e.g. a `--profile` flag)
+/// 2. the `TF_PROFILE` environment variable, when set and not empty
+/// 3. the `default_profile` key of the file
+///
+/// When none applies, the base configuration is returned. How the profile was
+/// selected is recorded in [`ProjectConfig::provenance`] under
+/// `active_profile` and shown by [`ProjectConfig::active_profile_summary`].
+///
+/// `TF__SECTION__FIELD` variables are then applied with
+/// [`ProjectConfig::apply_env_overrides`] and recorded as
+/// [`ValueSource::Env`], so only explicit overrides
+/// ([`ProjectConfig::set_override`]) are left to the caller.
+///
+/// # Errors
+///
+/// Any error from [`load_config`], from [`ProjectConfig::with_profile`] for
+/// the selected profile, or from [`ProjectConfig::apply_env_overrides`].
+///
+/// # Example
+///
+/// ```no_run
+/// use std::path::Path;
+/// use tf_config::load_config_with_profile;
+///
+/// // `tf --profile staging` passes Some("staging"); without the flag, TF_PROFILE
+/// // or default_profile decide
+/// let config = load_config_with_profile(Path::new("config.yaml"), None)?;
+/// println!("{}", config.active_profile_summary());
+/// # Ok::<(), tf_config::ConfigError>(())
+/// ```
+pub fn load_config_with_profile(
+    path: &Path,
+    profile: Option<&str>,
+) -> Result<ProjectConfig, ConfigError> {
+    let env_profile = std::env::var(PROFILE_ENV_VAR).ok();
+    load_config_with_profile_from(path, profile, env_profile.as_deref(), std::env::vars())
+}
+
+/// [`load_config_with_profile`] with the `TF_PROFILE` value and the variables
+/// for overrides passed in for testability.
+pub(crate) fn load_config_with_profile_from<I>(
+    path: &Path,
+    profile: Option<&str>,
+    env_profile: Option<&str>,
+    env_vars: I,
+) -> Result<ProjectConfig, ConfigError>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let config = load_config(path)?;
+    let mut config = apply_selected_profile(config, profile, env_profile)?;
+    config.apply_env_overrides_from(env_vars)?;
+    Ok(config)
+}
+
+/// Profile selection with the `TF_PROFILE` value passed in for testability.
+fn apply_selected_profile(
+    config: ProjectConfig,
+    explicit: Option<&str>,
+    env_profile: Option<&str>,
+) -> Result<ProjectConfig, ConfigError> {
+    let env_profile = env_profile.filter(|name| !name.trim().is_empty());
+    let selected = match (explicit, env_profile, config.default_profile.as_deref()) {
+        (Some(name), _, _) => Some((name.to_string(), ValueSource::Override)),
+        (None, Some(name), _) => Some((
+            name.trim().to_string(),
+            ValueSource::Env(PROFILE_ENV_VAR.to_string()),
+        )),
+        (None, None, Some(name)) => Some((name.to_string(), ValueSource::File)),
+        (None, None, None) => None,
+    };
+    let Some((name, source)) = selected else {
+        return Ok(config);
+    };
+    let mut merged = config.with_profile(&name)?;
+    merged.provenance.set("active_profile", source);
+    Ok(merged)
+}
+
+/// Load a configuration file and report every validation problem at once.
+///
+/// Unlike [`load_config`], which stops at the first invalid field, this function
+/// runs all checks on `project_name`, `output_folder`, `llm`, `jira`, `squash`
+/// and `templates`. When several fields are invalid, the error is
+/// [`ConfigError::Multiple`]; a single problem is returned as-is. Syntax and type
+/// errors detected while parsing are still reported alone, since the document
+/// cannot be validated further.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::path::Path;
+/// use tf_config::load_config_report;
+///
+/// match load_config_report(Path::new("config.yaml")) {
+///     Ok(config) => println!("Config OK: {}", config.project_name),
+///     Err(err) => {
+///         for problem in err.into_errors() {
+///             eprintln!("[ ] {}", problem);
+///         }
+///     }
+/// }
+/// ```
+pub fn load_config_report(path: &Path) -> Result<ProjectConfig, ConfigError> {
+    let config = parse_config_file(path)?;
+
+    match ConfigError::from_errors(collect_validation_errors(&config)) {
+        Some(err) => Err(config.locate_error(err)),
+        None => Ok(config),
+    }
+}
+
+/// Read and deserialize a configuration file without running semantic validation.
+///
+/// Serde errors are mapped to user-friendly [`ConfigError`] variants and
+/// environment variables are interpolated (see [`crate::interpolate`]). Callers
+/// that need to post-process values (e.g. secret resolution) before validation
+/// use this and call [`validate_config`] themselves.
+pub(crate) fn parse_config_file(path: &Path) -> Result<ProjectConfig, ConfigError> {
     // Check if file exists
     if !path.exists() {
         return Err(ConfigError::FileNotFound {
@@ -880,49 +1680,68 @@ pub fn load_config(path: &Path) -> Result<ProjectConfig, ConfigError> {
 
     // Read file content
     let content = std::fs::read_to_string(path)?;
+    let format = ConfigFormat::from_path(path);
+    let mut source = ConfigSource::new(path, &content);
+
+    // Merge `include:` fragments, then upgrade documents written for an older
+    // schema version. Syntax errors are left to the typed parse below, which
+    // reports them with more context.
+    let mut document = format.deserialize::<serde_yaml::Value>(&content).ok();
+    let mut rewritten = false;
+    if let Some(document) = document.as_mut() {
+        rewritten |= resolve_includes(path, document, &mut source)?;
+        rewritten |= migrate_for_load(document).map_err(|e| source.attach(e, &[]))?;
+    }
+    let source = Arc::new(source);
+    let parsed = match document {
+        Some(document) if rewritten => serde_yaml::from_value(document).map_err(FormatError::Yaml),
+        _ => format.deserialize(&content),
+    };
 
-    // Parse YAML with enhanced error handling for missing required fields and invalid enum values
-    let config: ProjectConfig = match serde_yaml::from_str(&content) {
+    // Parse with enhanced error handling for missing required fields and invalid enum values
+    let mut config: ProjectConfig = match parsed {
         Ok(c) => c,
         Err(e) => {
+            let parser_location = e
+                .position(&content)
+                .and_then(|(line, column)| source.locate_position(line, column));
             // Check if the error can be transformed into a user-friendly message
-            let err_msg = e.to_string();
-            if let Some(error_kind) = parse_serde_error(&err_msg) {
-                return Err(match error_kind {
-                    SerdeErrorKind::MissingField { field, hint } => {
-                        ConfigError::missing_field(field, hint)
-                    }
-                    SerdeErrorKind::InvalidEnumValue {
-                        field,
-                        reason,
-                        hint,
-                    } => ConfigError::invalid_value(field, reason, hint),
-                    SerdeErrorKind::InvalidEnumValueDynamic {
-                        field,
-                        reason,
-                        hint,
-                    } => ConfigError::invalid_value(field, reason, hint),
-                    SerdeErrorKind::UnknownField {
-                        field,
-                        location,
-                        hint,
-                    } => ConfigError::invalid_value(
-                        format!("{}.{}", location, field),
-                        "is not a recognized configuration field",
-                        hint,
-                    ),
+            if let Some(friendly) = friendly_serde_error(&e.message()) {
+                // Prefer the parser position (exact offending value) over the key index
+                return Err(match parser_location {
+                    Some(location) => friendly.with_location(location),
+                    None => source.attach(friendly, &[]),
                 });
             }
-            return Err(ConfigError::ParseError(e));
+            return Err(e.into_config_error(format, parser_location));
         }
     };
+    config.provenance = Provenance::from_file(&config, |field| source.contains_key(field));
+    config.source = Some(source);
+    config.base_dir = Some(config_base_dir(path));
 
-    // Validate required fields
-    validate_config(&config)?;
+    // Expand ${VAR} references before validation so expanded values are checked
+    interpolate_env_vars(&mut config).map_err(|e| config.locate_error(e))?;
 
     Ok(config)
 }
 
+/// Absolute directory of the configuration file, used to resolve relative paths.
+///
+/// Falls back to the parent as given when the current directory is unavailable.
+fn config_base_dir(path: &Path) -> PathBuf {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    if parent.is_absolute() {
+        return parent;
+    }
+    std::env::current_dir()
+        .map(|cwd| cwd.join(&parent))
+        .unwrap_or(parent)
+}
+
 /// Result of parsing a serde error for user-friendly transformation
 enum SerdeErrorKind {
     /// Missing required field
@@ -942,14 +1761,56 @@ enum SerdeErrorKind {
         reason: &'static str,
         hint: &'static str,
     },
+    /// Unknown enum variant, with the accepted values
+    UnknownVariant {
+        field: String,
+        reason: &'static str,
+        expected: Vec<String>,
+    },
     /// Unknown field (when deny_unknown_fields is active)
     UnknownField {
         field: String,
-        location: &'static str,
-        hint: &'static str,
+        location: String,
+        hint: String,
     },
 }
 
+/// User-friendly [`ConfigError`] for a serde error message, when
+/// [`parse_serde_error`] recognizes it.
+pub(crate) fn friendly_serde_error(err_msg: &str) -> Option<ConfigError> {
+    let friendly = match parse_serde_error(err_msg)? {
+        SerdeErrorKind::MissingField { field, hint } => ConfigError::missing_field(field, hint),
+        SerdeErrorKind::InvalidEnumValue {
+            field,
+            reason,
+            hint,
+        } => ConfigError::invalid_value(field, reason, hint),
+        SerdeErrorKind::InvalidEnumValueDynamic {
+            field,
+            reason,
+            hint,
+        } => ConfigError::invalid_value(field, reason, hint),
+        SerdeErrorKind::UnknownVariant {
+            field,
+            reason,
+            expected,
+        } => {
+            let values: Vec<String> = expected.iter().map(|v| format!("'{}'", v)).collect();
+            ConfigError::invalid_value(field, reason, format!("one of: {}", values.join(", ")))
+        }
+        SerdeErrorKind::UnknownField {
+            field,
+            location,
+            hint,
+        } => ConfigError::invalid_value(
+            format!("{}.{}", location, field),
+            "is not a recognized configuration field",
+            hint,
+        ),
+    };
+    Some(friendly)
+}
+
 /// Extract field path from serde_yaml error message patterns.
 ///
 /// Attempts to find field names in various formats:
@@ -1088,16 +1949,35 @@ fn parse_serde_error(err_msg: &str) -> Option<SerdeErrorKind> {
         }
     }
 
-    // Handle invalid enum variant errors for LlmMode
-    // serde_yaml format: "unknown variant `invalid`, expected one of `auto`, `local`, `cloud`"
-    if err_msg.contains("unknown variant")
-        && (err_msg.contains("auto") || err_msg.contains("local") || err_msg.contains("cloud"))
-    {
-        return Some(SerdeErrorKind::InvalidEnumValue {
-            field: "llm.mode",
-            reason: "is not a valid mode",
-            hint: "one of: 'auto', 'local', or 'cloud'",
-        });
+    // Handle invalid enum variant errors
+    // serde_yaml format: "llm.mode: unknown variant `invalid`, expected one of `auto`, `local`, `cloud`"
+    // The enum is the schema definition with exactly the expected values; the field is the
+    // path serde_yaml prefixes the message with, or the usual field of the enum (TOML, JSON)
+    if let Some(start) = err_msg.find("unknown variant `") {
+        let expected = expected_names(err_msg);
+        let path = error_path(err_msg, start);
+        let known = match enum_definition(&expected).as_deref() {
+            Some("LlmMode") => Some(("llm.mode", "is not a valid mode")),
+            Some("EntityType") => Some(("anonymization.entities", "is not a known entity type")),
+            Some("MergeStrategy") => Some(("profiles.merge", "is not a valid merge strategy")),
+            Some("FallbackMode") => Some(("resilience.fallback", "is not a valid fallback mode")),
+            _ => None,
+        };
+        if let Some((default_field, reason)) = known {
+            let field = match path {
+                // An entity is reported on the list, not on its index
+                Some(path) if default_field == "anonymization.entities" => path
+                    .trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+                    .to_string(),
+                Some(path) => path,
+                None => default_field.to_string(),
+            };
+            return Some(SerdeErrorKind::UnknownVariant {
+                field,
+                reason,
+                expected: expected.iter().map(|v| v.to_string()).collect(),
+            });
+        }
     }
 
     // Handle unknown field errors (deny_unknown_fields): "unknown field `field_name`, expected one of ..."
@@ -1109,17 +1989,25 @@ fn parse_serde_error(err_msg: &str) -> Option<SerdeErrorKind> {
             if let Some(end) = after_prefix.find('`') {
                 let field_name = after_prefix[..end].to_string();
 
-                // Determine the location based on "expected one of" list (more reliable than heuristic)
-                // serde_yaml includes valid field names in the error message
-                let location = detect_section_from_expected_fields(err_msg);
-
-                let hint = match location {
-                    "jira" => "valid jira fields are: endpoint, token",
-                    "squash" => "valid squash fields are: endpoint, username, password",
-                    "llm" => "valid llm fields are: mode, local_endpoint, local_model, cloud_enabled, cloud_endpoint, cloud_model, api_key, timeout_seconds, max_tokens",
-                    "templates" => "valid templates fields are: cr, ppt, anomaly",
-                    _ => "valid root fields are: project_name, output_folder, jira, squash, templates, llm",
+                // The section is recognized from the list of expected fields; serde_yaml
+                // also prefixes the message with the exact path (e.g. "jira.resilience: "),
+                // which is preferred as it tells sections sharing a struct apart
+                let section = detect_section_from_expected_fields(err_msg);
+                let location = error_path(err_msg, start).unwrap_or_else(|| section.to_string());
+
+                // The valid fields are the ones serde lists, so the hint follows the schema
+                let label = match section {
+                    "jira.scopes" => "scope",
+                    "checklist.criteria" => "criterion",
+                    "checklist.thresholds" => "thresholds",
+                    "anonymization.rules" => "rule",
+                    other => other,
                 };
+                let hint = format!(
+                    "valid {} fields are: {}",
+                    label,
+                    expected_names(err_msg).join(", ")
+                );
 
                 return Some(SerdeErrorKind::UnknownField {
                     field: field_name,
@@ -1399,59 +2287,107 @@ fn parse_serde_error(err_msg: &str) -> Option<SerdeErrorKind> {
 }
 
 /// Detect which configuration section an unknown field belongs to based on serde_yaml's
-/// "expected one of" list in the error message.
+/// list of expected fields in the error message.
 ///
 /// This is more reliable than simple string contains checks because it uses the
 /// actual valid field names enumerated by serde.
 fn detect_section_from_expected_fields(err_msg: &str) -> &'static str {
-    // Look for "expected one of" pattern which lists valid fields
-    if let Some(expected_pos) = err_msg.find("expected one of") {
+    // Look for the list of valid fields: "expected one of `a`, `b`, ..." or,
+    // for two-field sections, "expected `a` or `b`"
+    if let Some(expected_pos) = err_msg.find(", expected ") {
         let expected_section = &err_msg[expected_pos..];
+        let has = |field: &str| expected_section.contains(&format!("`{}`", field));
 
-        // Check for jira-specific fields (endpoint, token)
-        // Jira has only endpoint and token, so if we see both, it's jira
-        if expected_section.contains("`endpoint`")
-            && expected_section.contains("`token`")
-            && !expected_section.contains("`username`")
-            && !expected_section.contains("`mode`")
-        {
+        // Root-level fields (project_name, output_folder, jira, squash, etc.)
+        if has("project_name") && has("output_folder") {
+            return "root";
+        }
+
+        // Profile overrides (extends, merge, then the overridable sections)
+        if has("extends") && has("merge") {
+            return "profile";
+        }
+
+        // Jira has endpoint and token, but neither username (squash) nor mode (llm)
+        if has("endpoint") && has("token") && !has("username") && !has("mode") {
             return "jira";
         }
 
-        // Check for squash-specific fields (endpoint, username, password)
-        if expected_section.contains("`endpoint`")
-            && expected_section.contains("`username`")
-            && expected_section.contains("`password`")
-        {
+        // Squash fields (endpoint, username, password)
+        if has("endpoint") && has("username") && has("password") {
             return "squash";
         }
 
-        // Check for llm-specific fields (mode, local_endpoint, api_key, etc.)
-        if expected_section.contains("`mode`") && expected_section.contains("`local_endpoint`") {
+        // LLM fields (mode, local_endpoint, api_key, etc.)
+        if has("mode") && has("local_endpoint") {
             return "llm";
         }
 
-        // Check for templates-specific fields (cr, ppt, anomaly)
-        if expected_section.contains("`cr`")
-            && expected_section.contains("`ppt`")
-            && expected_section.contains("`anomaly`")
-        {
+        // Templates fields (cr, ppt, anomaly)
+        if has("cr") && has("ppt") && has("anomaly") {
             return "templates";
         }
 
-        // Check for root-level fields (project_name, output_folder, jira, squash, etc.)
-        if expected_section.contains("`project_name`")
-            && expected_section.contains("`output_folder`")
-        {
-            return "root";
+        // Network fields, shared by the root section and each integration
+        if has("proxy") && has("ca_bundle") {
+            return "network";
+        }
+
+        // Retry policy of an integration
+        if has("max_attempts") && has("fallback") {
+            return "resilience";
+        }
+
+        // Named Jira scopes
+        if has("jql") && has("window") {
+            return "jira.scopes";
+        }
+
+        // Checklist section, criteria and thresholds
+        if has("criteria") && has("thresholds") {
+            return "checklist";
+        }
+        if has("label") && has("weight") {
+            return "checklist.criteria";
+        }
+        if has("go") && has("clarify") {
+            return "checklist.thresholds";
+        }
+
+        // Anonymization section and custom rules
+        if has("entities") && has("rules") {
+            return "anonymization";
+        }
+        if has("pattern") && has("replacement") {
+            return "anonymization.rules";
         }
     }
 
-    // Fallback: if no "expected one of", use simple heuristics as backup
-    // but be more conservative to avoid mis-attribution
+    // Fallback: without a list of expected fields, attribute the field to the root
+    // rather than guessing a section
     "root"
 }
 
+/// Path serde_yaml prefixes the message with ("jira.resilience: unknown field ..."),
+/// ending at `start`; list indexes become segments (`criteria[0]` is `criteria.0`).
+fn error_path(err_msg: &str, start: usize) -> Option<String> {
+    err_msg[..start]
+        .strip_suffix(": ")
+        .filter(|path| !path.is_empty() && !path.contains(' '))
+        .map(|path| path.replace('[', ".").replace(']', ""))
+}
+
+/// Names listed after "expected" in a serde message: "expected one of `a`, `b`"
+/// or, for two names, "expected `a` or `b`".
+fn expected_names(err_msg: &str) -> Vec<&str> {
+    let Some(pos) = err_msg.find(", expected ") else {
+        return Vec::new();
+    };
+    let list = &err_msg[pos..];
+    let list = list.split(" at line ").next().unwrap_or(list);
+    list.split('`').skip(1).step_by(2).collect()
+}
+
 /// Validate that a URL has a valid format (scheme + valid host at minimum)
 ///
 /// A valid host must be either:
@@ -1466,7 +2402,7 @@ fn detect_section_from_expected_fields(err_msg: &str) -> &'static str {
 ///
 /// Invalid hosts like "...", "-start", "end-" are rejected.
 /// Ports must be valid (1-65535).
-fn is_valid_url(url: &str) -> bool {
+pub(crate) fn is_valid_url(url: &str) -> bool {
     // Must start with http:// or https:// (case-insensitive per RFC 3986)
     // RFC 3986 §3.1: "schemes are case-insensitive"
     let url_lower = url.to_lowercase();
@@ -1701,7 +2637,7 @@ fn is_valid_url(url: &str) -> bool {
 }
 
 /// Validate that a path string has valid format (not empty, no null bytes)
-fn is_valid_path_format(path: &str) -> bool {
+pub(crate) fn is_valid_path_format(path: &str) -> bool {
     let trimmed = path.trim();
     // Basic checks: non-empty and no null bytes
     if trimmed.is_empty() || path.contains('\0') {
@@ -1739,48 +2675,239 @@ fn is_coerced_scalar(s: &str) -> bool {
     matches!(lower.as_str(), "true" | "false" | "null" | "~")
 }
 
-/// Validate that a project name contains only valid characters
-/// Valid: alphanumeric, hyphens (-), underscores (_)
-fn is_valid_project_name(name: &str) -> bool {
-    !name.is_empty()
-        && name
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+/// Validate that a name used as an identifier (project name, profile, criterion
+/// id, anonymization rule, Jira scope or field) contains only valid characters
+/// Valid: alphanumeric, hyphens (-), underscores (_)
+pub(crate) fn is_valid_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Validate that a path does not contain path traversal sequences (..)
+///
+/// This checks for ".." as a path component, not just any occurrence of "..".
+/// For example:
+/// - "../parent" is unsafe (traversal at start)
+/// - "./data/../secrets" is unsafe (traversal in middle)
+/// - "file..txt" is safe (not a path component)
+/// - "my..folder/data" is safe (not a path component)
+pub(crate) fn is_safe_path(path: &str) -> bool {
+    // Split by both Unix and Windows path separators
+    for component in path.split(['/', '\\']) {
+        if component == ".." {
+            return false;
+        }
+    }
+    true
+}
+
+/// Validate template file extension using [`TemplateKind::expected_extension()`] as
+/// single source of truth (shared with `template::validate_extension`).
+fn has_valid_template_extension(path: &str, kind: crate::template::TemplateKind) -> bool {
+    let expected_no_dot = &kind.expected_extension()[1..]; // skip leading dot
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.eq_ignore_ascii_case(expected_no_dot))
+        .unwrap_or(false)
+}
+
+/// Validate configuration fields, failing on the first problem.
+///
+/// Use [`collect_validation_errors`] to get every problem at once.
+pub(crate) fn validate_config(config: &ProjectConfig) -> Result<(), ConfigError> {
+    match collect_validation_errors(config).into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Validate configuration fields and return every problem found.
+///
+/// Each field reports at most one error (the first failing check for that field),
+/// so the list can be displayed as a checklist. Errors are ordered by section:
+/// `project_name`, `output_folder`, `llm`, `jira`, `squash`, `network`,
+/// `templates`, `checklist`, then `anonymization`.
+pub(crate) fn collect_validation_errors(config: &ProjectConfig) -> Vec<ConfigError> {
+    let mut errors = Vec::new();
+
+    if let Err(e) = validate_project_name(&config.project_name) {
+        errors.push(e);
+    }
+    if let Err(e) = validate_output_folder(&config.output_folder) {
+        errors.push(e);
+    }
+    if let Some(ref llm) = config.llm {
+        validate_llm(llm, &mut errors);
+        if let Some(ref network) = llm.network {
+            validate_network("llm.", network, &mut errors);
+        }
+        if let Some(ref resilience) = llm.resilience {
+            validate_resilience(Integration::Llm, resilience, &mut errors);
+        }
+    }
+    if let Some(ref jira) = config.jira {
+        if let Err(e) =
+            validate_endpoint("jira.endpoint", &jira.endpoint, "https://jira.example.com")
+        {
+            errors.push(e);
+        }
+        if let Some(ref network) = jira.network {
+            validate_network("jira.", network, &mut errors);
+        }
+        if let Some(ref resilience) = jira.resilience {
+            validate_resilience(Integration::Jira, resilience, &mut errors);
+        }
+        validate_jira_scopes(jira, &mut errors);
+    }
+    if let Some(ref squash) = config.squash {
+        if let Err(e) = validate_endpoint(
+            "squash.endpoint",
+            &squash.endpoint,
+            "https://squash.example.com",
+        ) {
+            errors.push(e);
+        }
+        if let Some(ref network) = squash.network {
+            validate_network("squash.", network, &mut errors);
+        }
+        if let Some(ref resilience) = squash.resilience {
+            validate_resilience(Integration::Squash, resilience, &mut errors);
+        }
+    }
+    if let Some(ref network) = config.network {
+        validate_network("", network, &mut errors);
+    }
+    validate_client_certificates(config, &mut errors);
+    if let Some(ref templates) = config.templates {
+        validate_templates(templates, &mut errors);
+    }
+    if let Some(ref checklist) = config.checklist {
+        validate_checklist(checklist, &mut errors);
+    }
+    if let Some(ref anonymization) = config.anonymization {
+        validate_anonymization(anonymization, &mut errors);
+    }
+    validate_strict_mode(
+        config.anonymization.as_ref(),
+        config.llm.as_ref(),
+        &mut errors,
+    );
+    validate_profile_inheritance(config, &mut errors);
+    validate_profile_sections(config, &mut errors);
+    validate_default_profile(config, &mut errors);
+
+    errors
+}
+
+/// Check that profile sections replacing a base section carry the fields the
+/// section cannot do without.
+fn validate_profile_sections(config: &ProjectConfig, errors: &mut Vec<ConfigError>) {
+    let Some(profiles) = &config.profiles else {
+        return;
+    };
+    let mut names: Vec<&String> = profiles.keys().collect();
+    names.sort();
+
+    for name in names {
+        let profile = &profiles[name];
+        let default = profile.merge.unwrap_or_default();
+        let sections = [
+            (
+                "jira",
+                profile
+                    .jira
+                    .as_ref()
+                    .map(|j| (j.merge, j.endpoint.is_some())),
+            ),
+            (
+                "squash",
+                profile
+                    .squash
+                    .as_ref()
+                    .map(|s| (s.merge, s.endpoint.is_some())),
+            ),
+        ];
+        for (section, state) in sections {
+            if let Some((merge, false)) = state {
+                if merge.unwrap_or(default) == MergeStrategy::Replace {
+                    errors.push(ConfigError::missing_field(
+                        format!("profiles.{}.{}.endpoint", name, section),
+                        format!(
+                            "an endpoint, since this section replaces the base '{}' section (or set 'merge: deep' to inherit the base endpoint)",
+                            section
+                        ),
+                    ));
+                }
+            }
+        }
+    }
 }
 
-/// Validate that a path does not contain path traversal sequences (..)
-///
-/// This checks for ".." as a path component, not just any occurrence of "..".
-/// For example:
-/// - "../parent" is unsafe (traversal at start)
-/// - "./data/../secrets" is unsafe (traversal in middle)
-/// - "file..txt" is safe (not a path component)
-/// - "my..folder/data" is safe (not a path component)
-fn is_safe_path(path: &str) -> bool {
-    // Split by both Unix and Windows path separators
-    for component in path.split(['/', '\\']) {
-        if component == ".." {
-            return false;
+/// Check that every `extends` key names a defined profile and that no chain
+/// loops back on itself. Each cycle is reported once.
+fn validate_profile_inheritance(config: &ProjectConfig, errors: &mut Vec<ConfigError>) {
+    let Some(profiles) = &config.profiles else {
+        return;
+    };
+    let mut names: Vec<&String> = profiles
+        .iter()
+        .filter(|(_, p)| p.extends.is_some())
+        .map(|(name, _)| name)
+        .collect();
+    names.sort();
+
+    for name in names {
+        match config.profile_chain(name) {
+            Ok(_) => {}
+            Err(ConfigError::ProfileCycle { chain }) => {
+                // Report the cycle from its smallest member only
+                let members = &chain[..chain.len() - 1];
+                if chain[0] == *name && members.iter().all(|m| m >= name) {
+                    errors.push(ConfigError::ProfileCycle { chain });
+                }
+            }
+            Err(e) => {
+                // Unknown parents are reported by the profile that names them
+                if e.field() == Some(format!("profiles.{}.extends", name).as_str()) {
+                    errors.push(e);
+                }
+            }
         }
     }
-    true
 }
 
-/// Validate template file extension using [`TemplateKind::expected_extension()`] as
-/// single source of truth (shared with `template::validate_extension`).
-fn has_valid_template_extension(path: &str, kind: crate::template::TemplateKind) -> bool {
-    let expected_no_dot = &kind.expected_extension()[1..]; // skip leading dot
-    std::path::Path::new(path)
-        .extension()
-        .and_then(|e| e.to_str())
-        .map(|e| e.eq_ignore_ascii_case(expected_no_dot))
-        .unwrap_or(false)
+/// Check that `default_profile` names a defined profile.
+fn validate_default_profile(config: &ProjectConfig, errors: &mut Vec<ConfigError>) {
+    let Some(name) = &config.default_profile else {
+        return;
+    };
+    let mut available: Vec<&str> = config
+        .profiles
+        .iter()
+        .flat_map(|profiles| profiles.keys().map(String::as_str))
+        .collect();
+    if available.contains(&name.as_str()) {
+        return;
+    }
+    available.sort_unstable();
+    let hint = if available.is_empty() {
+        "a profile defined under 'profiles' (none are defined)".to_string()
+    } else {
+        format!("one of the defined profiles: {}", available.join(", "))
+    };
+    errors.push(ConfigError::invalid_value(
+        "default_profile",
+        format!("names unknown profile '{}'", name),
+        hint,
+    ));
 }
 
-/// Validate configuration fields
-fn validate_config(config: &ProjectConfig) -> Result<(), ConfigError> {
-    // Validate project_name is not empty
-    if config.project_name.trim().is_empty() {
+/// Validate project_name: non-empty, alphanumeric + hyphens + underscores only.
+fn validate_project_name(project_name: &str) -> Result<(), ConfigError> {
+    if project_name.trim().is_empty() {
         return Err(ConfigError::invalid_value(
             "project_name",
             "cannot be empty",
@@ -1788,8 +2915,7 @@ fn validate_config(config: &ProjectConfig) -> Result<(), ConfigError> {
         ));
     }
 
-    // Validate project_name format (alphanumeric + hyphens + underscores only)
-    if !is_valid_project_name(&config.project_name) {
+    if !is_valid_identifier(project_name) {
         return Err(ConfigError::invalid_value(
             "project_name",
             "contains invalid characters",
@@ -1797,11 +2923,16 @@ fn validate_config(config: &ProjectConfig) -> Result<(), ConfigError> {
         ));
     }
 
+    Ok(())
+}
+
+/// Validate output_folder format and reject path traversal.
+fn validate_output_folder(output_folder: &str) -> Result<(), ConfigError> {
     // Validate output_folder is not empty and has valid format
     // This rejects YAML boolean/null coercions like "true" or "null" (but allows numeric strings)
-    if !is_valid_path_format(&config.output_folder) {
+    if !is_valid_path_format(output_folder) {
         // Provide specific error message for coerced scalars
-        let reason = if is_coerced_scalar(&config.output_folder) {
+        let reason = if is_coerced_scalar(output_folder) {
             "has invalid type (boolean or null values are not valid paths)"
         } else {
             "cannot be empty or contain invalid characters (null bytes)"
@@ -1814,7 +2945,7 @@ fn validate_config(config: &ProjectConfig) -> Result<(), ConfigError> {
     }
 
     // Validate output_folder does not contain path traversal
-    if !is_safe_path(&config.output_folder) {
+    if !is_safe_path(output_folder) {
         return Err(ConfigError::invalid_value(
             "output_folder",
             "cannot contain path traversal sequences (..)",
@@ -1822,294 +2953,221 @@ fn validate_config(config: &ProjectConfig) -> Result<(), ConfigError> {
         ));
     }
 
-    // Validate LLM config if present
-    if let Some(ref llm) = config.llm {
-        // If mode is local, local_endpoint should be provided
-        if llm.mode == LlmMode::Local && llm.local_endpoint.is_none() {
-            return Err(ConfigError::missing_field(
-                "llm.local_endpoint",
-                "a local LLM endpoint URL (e.g., http://localhost:11434) when mode is 'local'",
-            ));
-        }
+    Ok(())
+}
 
-        // If mode is cloud, api_key is required and must not be empty
-        match &llm.api_key {
-            None => {
-                if llm.mode == LlmMode::Cloud {
-                    return Err(ConfigError::missing_field(
-                        "llm.api_key",
-                        "an API key for cloud LLM when mode is 'cloud'",
-                    ));
-                }
-            }
-            Some(key) if key.trim().is_empty() => {
-                if llm.mode == LlmMode::Cloud {
-                    return Err(ConfigError::invalid_value(
-                        "llm.api_key",
-                        "cannot be empty when mode is 'cloud'",
-                        "a non-empty API key string",
-                    ));
-                }
-            }
-            _ => {}
-        }
+/// Validate an integration endpoint URL (strict: no surrounding whitespace).
+fn validate_endpoint(field: &str, endpoint: &str, example: &str) -> Result<(), ConfigError> {
+    // Reject URLs with leading/trailing whitespace (strict validation)
+    if endpoint != endpoint.trim() {
+        return Err(ConfigError::invalid_value(
+            field,
+            "must not contain leading or trailing whitespace",
+            format!("a URL without extra spaces (e.g., '{}')", example),
+        ));
+    }
+    if !is_valid_url(endpoint) {
+        return Err(ConfigError::invalid_value(
+            field,
+            "must be a valid URL with host",
+            format!("a URL like {}", example),
+        ));
+    }
+    Ok(())
+}
 
-        // If mode is cloud and cloud_enabled is false, that's a configuration error
-        if llm.mode == LlmMode::Cloud && !llm.cloud_enabled {
-            return Err(ConfigError::invalid_value(
-                "llm.cloud_enabled",
-                "must be true when mode is 'cloud'",
-                "set cloud_enabled: true when using cloud mode",
-            ));
-        }
+/// Validate the LLM section, pushing at most one error per field.
+///
+/// Checks are pushed in a fixed order so that the first error matches the
+/// historical fail-fast behavior of [`validate_config`].
+fn validate_llm(llm: &LlmConfig, errors: &mut Vec<ConfigError>) {
+    let cloud = llm.mode == LlmMode::Cloud;
+    let auto_cloud = llm.mode == LlmMode::Auto && llm.cloud_enabled;
+
+    // If mode is local, local_endpoint should be provided
+    if llm.mode == LlmMode::Local && llm.local_endpoint.is_none() {
+        errors.push(ConfigError::missing_field(
+            "llm.local_endpoint",
+            "a local LLM endpoint URL (e.g., http://localhost:11434) when mode is 'local'",
+        ));
+    }
+
+    // If mode is cloud, api_key is required and must not be empty
+    match &llm.api_key {
+        None if cloud => errors.push(ConfigError::missing_field(
+            "llm.api_key",
+            "an API key for cloud LLM when mode is 'cloud'",
+        )),
+        Some(key) if key.trim().is_empty() && cloud => errors.push(ConfigError::invalid_value(
+            "llm.api_key",
+            "cannot be empty when mode is 'cloud'",
+            "a non-empty API key string",
+        )),
+        _ => {}
+    }
+
+    // If mode is cloud and cloud_enabled is false, that's a configuration error
+    if cloud && !llm.cloud_enabled {
+        errors.push(ConfigError::invalid_value(
+            "llm.cloud_enabled",
+            "must be true when mode is 'cloud'",
+            "set cloud_enabled: true when using cloud mode",
+        ));
+    }
+
+    // If mode is cloud, cloud_endpoint is required
+    if cloud && llm.cloud_endpoint.is_none() {
+        errors.push(ConfigError::missing_field(
+            "llm.cloud_endpoint",
+            "a cloud LLM endpoint URL (e.g., https://api.openai.com/v1) when mode is 'cloud'",
+        ));
+    }
 
-        // If mode is cloud, cloud_endpoint is required
-        if llm.mode == LlmMode::Cloud && llm.cloud_endpoint.is_none() {
-            return Err(ConfigError::missing_field(
+    // If mode is cloud, cloud_model is required and must not be empty
+    match &llm.cloud_model {
+        None if cloud => errors.push(ConfigError::missing_field(
+            "llm.cloud_model",
+            "a cloud model name (e.g., 'gpt-4o-mini', 'claude-3-sonnet') when mode is 'cloud'",
+        )),
+        Some(model) if model.trim().is_empty() && cloud => errors.push(ConfigError::invalid_value(
+            "llm.cloud_model",
+            "cannot be empty when mode is 'cloud'",
+            "a non-empty cloud model name (e.g., 'gpt-4o-mini')",
+        )),
+        _ => {}
+    }
+
+    // If mode is auto AND cloud_enabled=true, require cloud prerequisites
+    // This ensures that when the user intends to use cloud fallback, the config is valid
+    if auto_cloud {
+        // Require cloud_endpoint when cloud is enabled in auto mode
+        if llm.cloud_endpoint.is_none() {
+            errors.push(ConfigError::missing_field(
                 "llm.cloud_endpoint",
-                "a cloud LLM endpoint URL (e.g., https://api.openai.com/v1) when mode is 'cloud'",
+                "a cloud LLM endpoint URL when cloud_enabled is true in 'auto' mode (e.g., https://api.openai.com/v1)",
             ));
         }
 
-        // If mode is cloud, cloud_model is required and must not be empty
+        // Require cloud_model when cloud is enabled in auto mode
         match &llm.cloud_model {
-            None => {
-                if llm.mode == LlmMode::Cloud {
-                    return Err(ConfigError::missing_field(
-                        "llm.cloud_model",
-                        "a cloud model name (e.g., 'gpt-4o-mini', 'claude-3-sonnet') when mode is 'cloud'",
-                    ));
-                }
-            }
-            Some(model) if model.trim().is_empty() => {
-                if llm.mode == LlmMode::Cloud {
-                    return Err(ConfigError::invalid_value(
-                        "llm.cloud_model",
-                        "cannot be empty when mode is 'cloud'",
-                        "a non-empty cloud model name (e.g., 'gpt-4o-mini')",
-                    ));
-                }
-            }
+            None => errors.push(ConfigError::missing_field(
+                "llm.cloud_model",
+                "a cloud model name when cloud_enabled is true in 'auto' mode (e.g., 'gpt-4o-mini')",
+            )),
+            Some(model) if model.trim().is_empty() => errors.push(ConfigError::invalid_value(
+                "llm.cloud_model",
+                "cannot be empty when cloud_enabled is true in 'auto' mode",
+                "a non-empty cloud model name (e.g., 'gpt-4o-mini')",
+            )),
             _ => {}
         }
 
-        // If mode is auto AND cloud_enabled=true, require cloud prerequisites
-        // This ensures that when the user intends to use cloud fallback, the config is valid
-        if llm.mode == LlmMode::Auto && llm.cloud_enabled {
-            // Require cloud_endpoint when cloud is enabled in auto mode
-            if llm.cloud_endpoint.is_none() {
-                return Err(ConfigError::missing_field(
-                    "llm.cloud_endpoint",
-                    "a cloud LLM endpoint URL when cloud_enabled is true in 'auto' mode (e.g., https://api.openai.com/v1)",
-                ));
-            }
-
-            // Require cloud_model when cloud is enabled in auto mode
-            match &llm.cloud_model {
-                None => {
-                    return Err(ConfigError::missing_field(
-                        "llm.cloud_model",
-                        "a cloud model name when cloud_enabled is true in 'auto' mode (e.g., 'gpt-4o-mini')",
-                    ));
-                }
-                Some(model) if model.trim().is_empty() => {
-                    return Err(ConfigError::invalid_value(
-                        "llm.cloud_model",
-                        "cannot be empty when cloud_enabled is true in 'auto' mode",
-                        "a non-empty cloud model name (e.g., 'gpt-4o-mini')",
-                    ));
-                }
-                _ => {}
-            }
-
-            // Require api_key when cloud is enabled in auto mode
-            match &llm.api_key {
-                None => {
-                    return Err(ConfigError::missing_field(
-                        "llm.api_key",
-                        "an API key when cloud_enabled is true in 'auto' mode",
-                    ));
-                }
-                Some(key) if key.trim().is_empty() => {
-                    return Err(ConfigError::invalid_value(
-                        "llm.api_key",
-                        "cannot be empty when cloud_enabled is true in 'auto' mode",
-                        "a non-empty API key string",
-                    ));
-                }
-                _ => {}
-            }
-        }
-
-        // Validate local_endpoint URL format if provided
-        if let Some(ref endpoint) = llm.local_endpoint {
-            // Reject URLs with leading/trailing whitespace (strict validation)
-            if endpoint != endpoint.trim() {
-                return Err(ConfigError::invalid_value(
-                    "llm.local_endpoint",
-                    "must not contain leading or trailing whitespace",
-                    "a URL without extra spaces (e.g., 'http://localhost:11434')",
-                ));
-            }
-            if !is_valid_url(endpoint) {
-                return Err(ConfigError::invalid_value(
-                    "llm.local_endpoint",
-                    "must be a valid URL with host",
-                    "a URL like http://localhost:11434",
-                ));
-            }
+        // Require api_key when cloud is enabled in auto mode
+        match &llm.api_key {
+            None => errors.push(ConfigError::missing_field(
+                "llm.api_key",
+                "an API key when cloud_enabled is true in 'auto' mode",
+            )),
+            Some(key) if key.trim().is_empty() => errors.push(ConfigError::invalid_value(
+                "llm.api_key",
+                "cannot be empty when cloud_enabled is true in 'auto' mode",
+                "a non-empty API key string",
+            )),
+            _ => {}
         }
+    }
 
-        // Validate cloud_endpoint URL format if provided
-        if let Some(ref endpoint) = llm.cloud_endpoint {
-            // Reject URLs with leading/trailing whitespace (strict validation)
-            if endpoint != endpoint.trim() {
-                return Err(ConfigError::invalid_value(
-                    "llm.cloud_endpoint",
-                    "must not contain leading or trailing whitespace",
-                    "a URL without extra spaces (e.g., 'https://api.openai.com/v1')",
-                ));
-            }
-            if !is_valid_url(endpoint) {
-                return Err(ConfigError::invalid_value(
-                    "llm.cloud_endpoint",
-                    "must be a valid URL with host",
-                    "a URL like https://api.openai.com/v1",
-                ));
-            }
+    // Validate local_endpoint URL format if provided
+    if let Some(ref endpoint) = llm.local_endpoint {
+        if let Err(e) = validate_endpoint("llm.local_endpoint", endpoint, "http://localhost:11434")
+        {
+            errors.push(e);
         }
+    }
 
-        // Validate timeout_seconds is positive (must be > 0)
-        if llm.timeout_seconds == 0 {
-            return Err(ConfigError::invalid_value(
-                "llm.timeout_seconds",
-                "must be a positive integer (greater than 0)",
-                "a positive integer for timeout in seconds (e.g., 120)",
-            ));
+    // Validate cloud_endpoint URL format if provided
+    if let Some(ref endpoint) = llm.cloud_endpoint {
+        if let Err(e) =
+            validate_endpoint("llm.cloud_endpoint", endpoint, "https://api.openai.com/v1")
+        {
+            errors.push(e);
         }
+    }
 
-        // Validate max_tokens is positive (must be > 0)
-        if llm.max_tokens == 0 {
-            return Err(ConfigError::invalid_value(
-                "llm.max_tokens",
-                "must be a positive integer (greater than 0)",
-                "a positive integer for maximum tokens (e.g., 4096)",
-            ));
-        }
+    // Validate timeout_seconds is positive (must be > 0)
+    if llm.timeout_seconds == 0 {
+        errors.push(ConfigError::invalid_value(
+            "llm.timeout_seconds",
+            "must be a positive integer (greater than 0)",
+            "a positive integer for timeout in seconds (e.g., 120)",
+        ));
     }
 
-    // Validate Jira endpoint URL format if present
-    if let Some(ref jira) = config.jira {
-        // Reject URLs with leading/trailing whitespace (strict validation)
-        if jira.endpoint != jira.endpoint.trim() {
-            return Err(ConfigError::invalid_value(
-                "jira.endpoint",
-                "must not contain leading or trailing whitespace",
-                "a URL without extra spaces (e.g., 'https://jira.example.com')",
-            ));
-        }
-        if !is_valid_url(&jira.endpoint) {
-            return Err(ConfigError::invalid_value(
-                "jira.endpoint",
-                "must be a valid URL with host",
-                "a URL like https://jira.example.com",
-            ));
-        }
+    // Validate max_tokens is positive (must be > 0)
+    if llm.max_tokens == 0 {
+        errors.push(ConfigError::invalid_value(
+            "llm.max_tokens",
+            "must be a positive integer (greater than 0)",
+            "a positive integer for maximum tokens (e.g., 4096)",
+        ));
     }
+}
 
-    // Validate Squash endpoint URL format if present
-    if let Some(ref squash) = config.squash {
-        // Reject URLs with leading/trailing whitespace (strict validation)
-        if squash.endpoint != squash.endpoint.trim() {
-            return Err(ConfigError::invalid_value(
-                "squash.endpoint",
-                "must not contain leading or trailing whitespace",
-                "a URL without extra spaces (e.g., 'https://squash.example.com')",
+/// Validate template paths, pushing at most one error per template.
+fn validate_templates(templates: &TemplatesConfig, errors: &mut Vec<ConfigError>) {
+    use crate::template::TemplateKind;
+
+    let entries = [
+        (
+            "templates.cr",
+            templates.cr.as_deref(),
+            TemplateKind::Cr,
+            "./templates/cr.md",
+            "must be a Markdown file",
+            "a .md file path like './templates/cr.md'",
+        ),
+        (
+            "templates.ppt",
+            templates.ppt.as_deref(),
+            TemplateKind::Ppt,
+            "./templates/report.pptx",
+            "must be a PowerPoint file",
+            "a .pptx file path like './templates/report.pptx'",
+        ),
+        (
+            "templates.anomaly",
+            templates.anomaly.as_deref(),
+            TemplateKind::Anomaly,
+            "./templates/anomaly.md",
+            "must be a Markdown file",
+            "a .md file path like './templates/anomaly.md'",
+        ),
+    ];
+
+    for (field, path, kind, example, extension_reason, extension_hint) in entries {
+        let Some(path) = path else {
+            continue;
+        };
+        if !is_valid_path_format(path) {
+            errors.push(ConfigError::invalid_value(
+                field,
+                "must be a valid file path",
+                format!("a path like '{}'", example),
             ));
-        }
-        if !is_valid_url(&squash.endpoint) {
-            return Err(ConfigError::invalid_value(
-                "squash.endpoint",
-                "must be a valid URL with host",
-                "a URL like https://squash.example.com",
+        } else if !is_safe_path(path) {
+            errors.push(ConfigError::invalid_value(
+                field,
+                "cannot contain path traversal sequences (..)",
+                format!("a direct path without '..' (e.g., '{}')", example),
+            ));
+        } else if !has_valid_template_extension(path, kind) {
+            errors.push(ConfigError::invalid_value(
+                field,
+                extension_reason,
+                extension_hint,
             ));
         }
     }
-
-    // Validate template paths if provided
-    if let Some(ref templates) = config.templates {
-        if let Some(ref cr) = templates.cr {
-            if !is_valid_path_format(cr) {
-                return Err(ConfigError::invalid_value(
-                    "templates.cr",
-                    "must be a valid file path",
-                    "a path like './templates/cr.md'",
-                ));
-            }
-            if !is_safe_path(cr) {
-                return Err(ConfigError::invalid_value(
-                    "templates.cr",
-                    "cannot contain path traversal sequences (..)",
-                    "a direct path without '..' (e.g., './templates/cr.md')",
-                ));
-            }
-            if !has_valid_template_extension(cr, crate::template::TemplateKind::Cr) {
-                return Err(ConfigError::invalid_value(
-                    "templates.cr",
-                    "must be a Markdown file",
-                    "a .md file path like './templates/cr.md'",
-                ));
-            }
-        }
-        if let Some(ref ppt) = templates.ppt {
-            if !is_valid_path_format(ppt) {
-                return Err(ConfigError::invalid_value(
-                    "templates.ppt",
-                    "must be a valid file path",
-                    "a path like './templates/ppt.pptx'",
-                ));
-            }
-            if !is_safe_path(ppt) {
-                return Err(ConfigError::invalid_value(
-                    "templates.ppt",
-                    "cannot contain path traversal sequences (..)",
-                    "a direct path without '..' (e.g., './templates/report.pptx')",
-                ));
-            }
-            if !has_valid_template_extension(ppt, crate::template::TemplateKind::Ppt) {
-                return Err(ConfigError::invalid_value(
-                    "templates.ppt",
-                    "must be a PowerPoint file",
-                    "a .pptx file path like './templates/report.pptx'",
-                ));
-            }
-        }
-        if let Some(ref anomaly) = templates.anomaly {
-            if !is_valid_path_format(anomaly) {
-                return Err(ConfigError::invalid_value(
-                    "templates.anomaly",
-                    "must be a valid file path",
-                    "a path like './templates/anomaly.md'",
-                ));
-            }
-            if !is_safe_path(anomaly) {
-                return Err(ConfigError::invalid_value(
-                    "templates.anomaly",
-                    "cannot contain path traversal sequences (..)",
-                    "a direct path without '..' (e.g., './templates/anomaly.md')",
-                ));
-            }
-            if !has_valid_template_extension(anomaly, crate::template::TemplateKind::Anomaly) {
-                return Err(ConfigError::invalid_value(
-                    "templates.anomaly",
-                    "must be a Markdown file",
-                    "a .md file path like './templates/anomaly.md'",
-                ));
-            }
-        }
-    }
-
-    Ok(())
 }
 
 #[cfg(test)]
@@ -2264,6 +3322,10 @@ jira:
         let jira = JiraConfig {
             endpoint: "https://jira.example.com".to_string(),
             token: Some("super_secret_token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         };
 
         let debug_output = format!("{:?}", jira);
@@ -2278,6 +3340,8 @@ jira:
             endpoint: "https://squash.example.com".to_string(),
             username: Some("user".to_string()),
             password: Some("secret_password".to_string()),
+            network: None,
+            resilience: None,
         };
 
         let debug_output = format!("{:?}", squash);
@@ -2298,6 +3362,8 @@ jira:
             api_key: Some("sk-secret-api-key-12345".to_string()),
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         };
 
         let debug_output = format!("{:?}", llm);
@@ -2310,6 +3376,10 @@ jira:
         let jira = JiraConfig {
             endpoint: "https://jira.example.com".to_string(),
             token: Some("my_token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         };
 
         let redacted = jira.redacted();
@@ -2324,6 +3394,8 @@ jira:
             endpoint: "https://squash.example.com".to_string(),
             username: Some("testuser".to_string()),
             password: Some("secret_password".to_string()),
+            network: None,
+            resilience: None,
         };
 
         let redacted = squash.redacted();
@@ -2345,6 +3417,8 @@ jira:
             api_key: Some("sk-super-secret-key".to_string()),
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         };
 
         let redacted = llm.redacted();
@@ -2973,22 +4047,22 @@ llm:
     #[test]
     fn test_project_name_valid_formats() {
         // Valid: alphanumeric, hyphens, underscores
-        assert!(is_valid_project_name("my-project"));
-        assert!(is_valid_project_name("test_framework"));
-        assert!(is_valid_project_name("MyProject123"));
-        assert!(is_valid_project_name("a"));
-        assert!(is_valid_project_name("test-project_v2"));
+        assert!(is_valid_identifier("my-project"));
+        assert!(is_valid_identifier("test_framework"));
+        assert!(is_valid_identifier("MyProject123"));
+        assert!(is_valid_identifier("a"));
+        assert!(is_valid_identifier("test-project_v2"));
     }
 
     #[test]
     fn test_project_name_invalid_formats() {
         // Invalid: spaces, special chars, etc.
-        assert!(!is_valid_project_name("my project"));
-        assert!(!is_valid_project_name("test.project"));
-        assert!(!is_valid_project_name("project@name"));
-        assert!(!is_valid_project_name("project/name"));
-        assert!(!is_valid_project_name(""));
-        assert!(!is_valid_project_name("项目")); // non-ASCII
+        assert!(!is_valid_identifier("my project"));
+        assert!(!is_valid_identifier("test.project"));
+        assert!(!is_valid_identifier("project@name"));
+        assert!(!is_valid_identifier("project/name"));
+        assert!(!is_valid_identifier(""));
+        assert!(!is_valid_identifier("项目")); // non-ASCII
     }
 
     #[test]
@@ -3037,6 +4111,8 @@ output_folder: "./output"
             api_key: Some("sk-super-secret-key-12345".to_string()),
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         };
 
         let debug_output = format!("{:?}", llm);
@@ -3059,6 +4135,8 @@ output_folder: "./output"
             api_key: Some("secret-api-key".to_string()),
             timeout_seconds: 60,
             max_tokens: 2048,
+            network: None,
+            resilience: None,
         };
 
         let redacted = llm.redacted();
@@ -3138,6 +4216,78 @@ templates:
         assert!(err_msg.contains("unknown") || err_msg.contains("not a recognized"));
     }
 
+    #[test]
+    fn test_unknown_field_names_its_section() {
+        let cases = [
+            (
+                "network:\n  proxi: \"http://proxy:3128\"\n",
+                "network.proxi",
+                "valid network fields",
+            ),
+            (
+                "checklist:\n  critera: []\n",
+                "checklist.critera",
+                "valid checklist fields",
+            ),
+            (
+                "anonymization:\n  rules:\n    - nam: \"x\"\n",
+                "anonymization.rules.0.nam",
+                "valid rule fields",
+            ),
+            (
+                "jira:\n  endpoint: \"https://jira.example.com\"\n  scope: {}\n",
+                "jira.scope",
+                "scopes",
+            ),
+            (
+                "llm:\n  resilience:\n    max_attempt: 2\n",
+                "llm.resilience.max_attempt",
+                "valid resilience fields",
+            ),
+            (
+                "profiles:\n  dev:\n    netwrk: {}\n",
+                "profiles.dev.netwrk",
+                "valid profile fields",
+            ),
+        ];
+        for (section, field, hint) in cases {
+            let yaml = format!(
+                "project_name: \"test\"\noutput_folder: \"./output\"\n{}",
+                section
+            );
+            let file = create_temp_config(&yaml);
+            let err = load_config(file.path()).unwrap_err();
+            assert_eq!(err.field(), Some(field), "{}", err);
+            assert!(err.to_string().contains(hint), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_unknown_field_hint_lists_the_schema_fields() {
+        let schema = crate::schema::config_schema();
+        for (section, definition) in [("jira", "JiraConfig"), ("network", "NetworkConfig")] {
+            let yaml = format!(
+                "project_name: \"test\"\noutput_folder: \"./output\"\n{}:\n  endpoint: \"https://jira.example.com\"\n  typo: 1\n",
+                section
+            );
+            let message = serde_yaml::from_str::<ProjectConfig>(&yaml)
+                .unwrap_err()
+                .to_string();
+            let Some(SerdeErrorKind::UnknownField { hint, .. }) = parse_serde_error(&message)
+            else {
+                panic!("unknown field not recognized: {}", message);
+            };
+            let fields: Vec<&String> = schema["definitions"][definition]["properties"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect();
+            for field in fields {
+                assert!(hint.contains(field.as_str()), "{} misses {}", hint, field);
+            }
+        }
+    }
+
     #[test]
     fn test_hostname_validation_rfc1123_labels() {
         // Valid hostnames
@@ -4046,6 +5196,10 @@ llm:
         let jira = JiraConfig {
             endpoint: "https://jira.example.com?token=secret".to_string(),
             token: Some("other_secret".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         };
 
         let debug_output = format!("{:?}", jira);
@@ -4063,6 +5217,8 @@ llm:
             endpoint: "https://squash.example.com?password=secret123".to_string(),
             username: Some("user".to_string()),
             password: Some("other_secret".to_string()),
+            network: None,
+            resilience: None,
         };
 
         let debug_output = format!("{:?}", squash);
@@ -4079,6 +5235,10 @@ llm:
         let jira = JiraConfig {
             endpoint: "https://jira.example.com?api_key=sk-12345".to_string(),
             token: Some("token_value".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         };
 
         let redacted = jira.redacted();
@@ -4222,6 +5382,8 @@ jira:
             api_key: Some("other_secret".to_string()),
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         };
 
         let debug_output = format!("{:?}", llm);
@@ -4246,6 +5408,8 @@ jira:
             api_key: Some("other_secret".to_string()),
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         };
 
         let redacted = llm.redacted();
@@ -4770,6 +5934,8 @@ llm:
             api_key: None,
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         };
 
         let debug_output = format!("{:?}", llm);
@@ -4803,6 +5969,8 @@ llm:
             api_key: None,
             timeout_seconds: 60,
             max_tokens: 2048,
+            network: None,
+            resilience: None,
         };
 
         let redacted = llm.redacted();
@@ -4836,6 +6004,8 @@ llm:
             api_key: None,
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         };
 
         let debug_output = format!("{:?}", llm);
@@ -5621,6 +6791,8 @@ llm:
         let nonexistent = dir.path().join("this_folder_does_not_exist");
 
         let config = ProjectConfig {
+            config_version: CURRENT_CONFIG_VERSION,
+            include: None,
             project_name: "test".to_string(),
             output_folder: nonexistent.to_string_lossy().to_string(),
             jira: None,
@@ -5628,7 +6800,14 @@ llm:
             templates: None,
             llm: None,
             profiles: None,
+            checklist: None,
+            anonymization: None,
+            network: None,
+            default_profile: None,
             active_profile: None,
+            source: None,
+            base_dir: None,
+            provenance: Default::default(),
         };
 
         let warning = config.check_output_folder_exists();
@@ -5652,6 +6831,8 @@ llm:
         std::fs::write(&file_path, "content").unwrap();
 
         let config = ProjectConfig {
+            config_version: CURRENT_CONFIG_VERSION,
+            include: None,
             project_name: "test".to_string(),
             output_folder: file_path.to_string_lossy().to_string(),
             jira: None,
@@ -5659,7 +6840,14 @@ llm:
             templates: None,
             llm: None,
             profiles: None,
+            checklist: None,
+            anonymization: None,
+            network: None,
+            default_profile: None,
             active_profile: None,
+            source: None,
+            base_dir: None,
+            provenance: Default::default(),
         };
 
         let warning = config.check_output_folder_exists();
@@ -5681,6 +6869,8 @@ llm:
         let dir = tempfile::tempdir().unwrap();
 
         let config = ProjectConfig {
+            config_version: CURRENT_CONFIG_VERSION,
+            include: None,
             project_name: "test".to_string(),
             output_folder: dir.path().to_string_lossy().to_string(),
             jira: None,
@@ -5688,7 +6878,14 @@ llm:
             templates: None,
             llm: None,
             profiles: None,
+            checklist: None,
+            anonymization: None,
+            network: None,
+            default_profile: None,
             active_profile: None,
+            source: None,
+            base_dir: None,
+            provenance: Default::default(),
         };
 
         let warning = config.check_output_folder_exists();
@@ -5698,10 +6895,41 @@ llm:
         );
     }
 
+    #[test]
+    fn test_load_config_records_base_dir_for_relative_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("output")).unwrap();
+        let config_path = dir.path().join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "project_name: \"demo\"\noutput_folder: \"output\"\n",
+        )
+        .unwrap();
+
+        let config = load_config(&config_path).unwrap();
+        assert_eq!(config.base_dir.as_deref(), Some(dir.path()));
+        assert_eq!(config.output_folder_path(), dir.path().join("output"));
+        // Found relative to the config file, whatever the working directory
+        assert!(config.check_output_folder_exists().is_none());
+
+        let merged = config.apply_profile(&ProfileOverride::default());
+        assert_eq!(merged.base_dir, config.base_dir);
+    }
+
+    #[test]
+    fn test_resolve_path_without_base_dir_is_unchanged() {
+        let config: ProjectConfig =
+            serde_yaml::from_str("project_name: \"demo\"\noutput_folder: \"./out\"\n").unwrap();
+        assert_eq!(config.output_folder_path(), Path::new("./out"));
+        assert_eq!(config.resolve_path("/abs/cr.md"), Path::new("/abs/cr.md"));
+    }
+
     #[test]
     fn test_active_profile_summary_no_active_profile() {
         // P1: With no active profile, summary starts with "No profile active"
         let config = ProjectConfig {
+            config_version: CURRENT_CONFIG_VERSION,
+            include: None,
             project_name: "my-project".to_string(),
             output_folder: "./output".to_string(),
             jira: None,
@@ -5709,7 +6937,14 @@ llm:
             templates: None,
             llm: None,
             profiles: None,
+            checklist: None,
+            anonymization: None,
+            network: None,
+            default_profile: None,
             active_profile: None,
+            source: None,
+            base_dir: None,
+            provenance: Default::default(),
         };
 
         let summary = config.active_profile_summary();
@@ -5749,16 +6984,24 @@ llm:
     fn test_active_profile_summary_with_active_profile() {
         // P1: With an active profile, summary shows profile name and configured services
         let config = ProjectConfig {
+            config_version: CURRENT_CONFIG_VERSION,
+            include: None,
             project_name: "my-project".to_string(),
             output_folder: "./dev-output".to_string(),
             jira: Some(JiraConfig {
                 endpoint: "https://jira.dev.example.com".to_string(),
                 token: Some("secret-token".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
             }),
             squash: Some(SquashConfig {
                 endpoint: "https://squash.dev.example.com".to_string(),
                 username: Some("user".to_string()),
                 password: Some("pass".to_string()),
+                network: None,
+                resilience: None,
             }),
             templates: None,
             llm: Some(LlmConfig {
@@ -5771,9 +7014,18 @@ llm:
                 api_key: None,
                 timeout_seconds: 120,
                 max_tokens: 4096,
+                network: None,
+                resilience: None,
             }),
             profiles: None,
+            checklist: None,
+            anonymization: None,
+            network: None,
+            default_profile: None,
             active_profile: Some("dev".to_string()),
+            source: None,
+            base_dir: None,
+            provenance: Default::default(),
         };
 
         let summary = config.active_profile_summary();
@@ -5932,4 +7184,175 @@ llm:
         let redacted = redact_url_sensitive_params("");
         assert_eq!(redacted, "", "Empty string should return empty string");
     }
+
+    // ===== Validation report: collect all errors =====
+
+    #[test]
+    fn test_load_config_report_single_error_is_not_wrapped() {
+        let yaml = r#"
+project_name: "test"
+output_folder: "./output"
+jira:
+  endpoint: "invalid"
+"#;
+        let file = create_temp_config(yaml);
+        let err = load_config_report(file.path()).unwrap_err();
+        assert!(
+            matches!(err, ConfigError::InvalidValue { ref field, .. } if field == "jira.endpoint")
+        );
+    }
+
+    #[test]
+    fn test_load_config_report_valid_config() {
+        let yaml = r#"
+project_name: "test"
+output_folder: "./output"
+"#;
+        let file = create_temp_config(yaml);
+        let config = load_config_report(file.path()).unwrap();
+        assert_eq!(config.project_name, "test");
+        assert!(config.validation_errors().is_empty());
+    }
+
+    #[test]
+    fn test_multiple_error_message_is_numbered_checklist() {
+        let yaml = r#"
+project_name: ""
+output_folder: ""
+squash:
+  endpoint: " https://squash.example.com"
+"#;
+        let file = create_temp_config(yaml);
+        let err = load_config_report(file.path()).unwrap_err();
+        let msg = err.to_string();
+
+        assert!(msg.contains("3 problems found"), "got: {}", msg);
+        assert!(msg.contains("1. Invalid configuration: field 'project_name'"));
+        assert!(msg.contains("2. Invalid configuration: field 'output_folder'"));
+        assert!(msg.contains("3. Invalid configuration: field 'squash.endpoint'"));
+        assert_eq!(err.into_errors().len(), 3);
+    }
+
+    #[test]
+    fn test_report_keeps_one_error_per_template_field() {
+        let yaml = r#"
+project_name: "test"
+output_folder: "./output"
+templates:
+  cr: "../cr.txt"
+  ppt: "report.ppt"
+  anomaly: ""
+"#;
+        let file = create_temp_config(yaml);
+        let errors = load_config_report(file.path()).unwrap_err().into_errors();
+        let reasons: Vec<String> = errors
+            .iter()
+            .map(|e| match e {
+                ConfigError::InvalidValue { field, reason, .. } => format!("{}: {}", field, reason),
+                other => panic!("unexpected error: {:?}", other),
+            })
+            .collect();
+
+        assert_eq!(
+            reasons,
+            vec![
+                "templates.cr: cannot contain path traversal sequences (..)",
+                "templates.ppt: must be a PowerPoint file",
+                "templates.anomaly: must be a valid file path",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_profile_selection_precedence() {
+        let config: ProjectConfig = serde_yaml::from_str(
+            "project_name: \"demo\"\noutput_folder: \"./output\"\ndefault_profile: \"dev\"\nprofiles:\n  dev:\n    output_folder: \"./dev\"\n  ci:\n    output_folder: \"./ci\"\n  prod:\n    output_folder: \"./prod\"\n",
+        )
+        .unwrap();
+
+        let explicit = apply_selected_profile(config.clone(), Some("prod"), Some("ci")).unwrap();
+        assert_eq!(explicit.output_folder, "./prod");
+        assert!(explicit
+            .active_profile_summary()
+            .contains("Active profile: prod, selected explicitly"));
+
+        let env = apply_selected_profile(config.clone(), None, Some("ci")).unwrap();
+        assert_eq!(env.active_profile.as_deref(), Some("ci"));
+        assert!(env
+            .active_profile_summary()
+            .contains("Active profile: ci, selected by TF_PROFILE"));
+
+        // An empty TF_PROFILE is ignored
+        let default = apply_selected_profile(config.clone(), None, Some("")).unwrap();
+        assert_eq!(default.active_profile.as_deref(), Some("dev"));
+        assert_eq!(
+            default.provenance.get("active_profile"),
+            Some(&ValueSource::File)
+        );
+        assert!(default
+            .active_profile_summary()
+            .contains("Active profile: dev, selected by default_profile"));
+
+        let mut no_default = config;
+        no_default.default_profile = None;
+        let base = apply_selected_profile(no_default, None, None).unwrap();
+        assert!(base.active_profile.is_none());
+    }
+
+    #[test]
+    fn test_default_profile_must_name_a_profile() {
+        let config: ProjectConfig = serde_yaml::from_str(
+            "project_name: \"demo\"\noutput_folder: \"./output\"\ndefault_profile: \"stagin\"\nprofiles:\n  staging: {}\n  dev: {}\n",
+        )
+        .unwrap();
+        let errors = collect_validation_errors(&config);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field(), Some("default_profile"));
+        assert!(
+            errors[0].to_string().contains("dev, staging"),
+            "{}",
+            errors[0]
+        );
+
+        let err = apply_selected_profile(config, Some("nope"), None).unwrap_err();
+        assert!(matches!(err, ConfigError::ProfileNotFound { .. }));
+    }
+
+    #[test]
+    fn test_load_config_with_profile_applies_env_overrides_after_the_profile() {
+        let file = create_temp_config(
+            "project_name: \"demo\"\noutput_folder: \"./output\"\nprofiles:\n  ci:\n    output_folder: \"./ci\"\n    llm:\n      mode: \"local\"\n      local_endpoint: \"http://localhost:11434\"\n",
+        );
+        let vars = || {
+            vec![
+                ("TF__LLM__TIMEOUT_SECONDS".to_string(), "300".to_string()),
+                ("HOME".to_string(), "/home/qa".to_string()),
+            ]
+        };
+
+        let config = load_config_with_profile_from(file.path(), Some("ci"), None, vars()).unwrap();
+        assert_eq!(config.output_folder, "./ci");
+        assert_eq!(config.llm.as_ref().unwrap().timeout_seconds, 300);
+        assert_eq!(
+            config.provenance.get("llm.timeout_seconds"),
+            Some(&ValueSource::Env("TF__LLM__TIMEOUT_SECONDS".into()))
+        );
+        assert_eq!(
+            config.provenance.get("output_folder"),
+            Some(&ValueSource::Profile("ci".into()))
+        );
+
+        // Overrides are validated with the merged configuration
+        let err = load_config_with_profile_from(
+            file.path(),
+            Some("ci"),
+            None,
+            vec![(
+                "TF__LLM__LOCAL_ENDPOINT".to_string(),
+                "not a url".to_string(),
+            )],
+        )
+        .unwrap_err();
+        assert_eq!(err.field(), Some("llm.local_endpoint"));
+    }
 }
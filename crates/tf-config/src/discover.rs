@@ -0,0 +1,258 @@
+//! Configuration file discovery.
+//!
+//! [`discover_config`] finds the project configuration without an explicit path,
+//! the way `git` finds its repository:
+//!
+//! 1. If the `TF_CONFIG` environment variable is set, it names the file to load
+//!    (relative paths are taken from the start directory).
+//! 2. Otherwise the start directory and then each parent is searched for, in order,
+//!    `config.yaml`, `.tf/config.yaml` and `tf.yaml`. Only YAML names are
+//!    searched: a TOML or JSON configuration is loaded through `TF_CONFIG` or
+//!    an explicit path.
+//! 3. The search stops after the first directory containing `.git` (the repository
+//!    root), at a filesystem boundary (mount point, on Unix), or at the root.
+//!
+//! The file found is loaded with
+//! [`load_config_with_profile`](crate::load_config_with_profile), so
+//! `TF_PROFILE` and the `default_profile` key select the profile, and `TF__*`
+//! variables override values, as they do for an explicit path.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::discover_config;
+//!
+//! let (path, config) = discover_config(Path::new(".")).unwrap();
+//! println!("Loaded {} from {}", config.project_name, path.display());
+//! ```
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use crate::config::{load_config_with_profile_from, ProjectConfig, PROFILE_ENV_VAR};
+use crate::error::ConfigError;
+
+/// Environment variable naming the configuration file explicitly.
+pub const CONFIG_ENV_VAR: &str = "TF_CONFIG";
+
+/// File names searched in each directory, in priority order.
+///
+/// YAML only; `config.toml` or `config.json` must be named with
+/// [`CONFIG_ENV_VAR`] or passed to [`load_config`](crate::load_config).
+pub const CONFIG_FILE_NAMES: &[&str] = &["config.yaml", ".tf/config.yaml", "tf.yaml"];
+
+/// Find and load the configuration for `start` (usually the working directory).
+///
+/// Returns the path of the file found together with the loaded configuration,
+/// with the profile selected by `TF_PROFILE` or `default_profile` applied.
+///
+/// # Errors
+///
+/// - [`ConfigError::ConfigNotDiscovered`] if no file is found before the search stops
+/// - [`ConfigError::FileNotFound`] if `TF_CONFIG` names a file that does not exist
+/// - Any error from [`load_config_with_profile`](crate::load_config_with_profile) for the file found
+pub fn discover_config(start: &Path) -> Result<(PathBuf, ProjectConfig), ConfigError> {
+    discover_config_with(
+        start,
+        std::env::var_os(CONFIG_ENV_VAR),
+        std::env::var(PROFILE_ENV_VAR).ok(),
+        std::env::vars(),
+    )
+}
+
+/// Discovery and loading, with the `TF_CONFIG` and `TF_PROFILE` values and the
+/// variables for `TF__*` overrides passed in for testability.
+fn discover_config_with<I>(
+    start: &Path,
+    env_config: Option<OsString>,
+    env_profile: Option<String>,
+    env_vars: I,
+) -> Result<(PathBuf, ProjectConfig), ConfigError>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let path = find_config_with(start, env_config)?;
+    let config = load_config_with_profile_from(&path, None, env_profile.as_deref(), env_vars)?;
+    Ok((path, config))
+}
+
+/// Find the configuration file for `start` without loading it.
+///
+/// Same search as [`discover_config`].
+pub fn find_config(start: &Path) -> Result<PathBuf, ConfigError> {
+    find_config_with(start, std::env::var_os(CONFIG_ENV_VAR))
+}
+
+/// Search implementation, with the `TF_CONFIG` value passed in for testability.
+fn find_config_with(start: &Path, env_value: Option<OsString>) -> Result<PathBuf, ConfigError> {
+    let start = absolute(start);
+
+    if let Some(value) = env_value.filter(|v| !v.is_empty()) {
+        let path = start.join(value);
+        if !path.is_file() {
+            return Err(ConfigError::FileNotFound { path });
+        }
+        return Ok(path);
+    }
+
+    let start_device = device_id(&start);
+    let mut dir = start.as_path();
+    loop {
+        for name in CONFIG_FILE_NAMES {
+            let candidate = dir.join(name);
+            if candidate.is_file() {
+                return Ok(candidate);
+            }
+        }
+
+        let at_git_root = dir.join(".git").exists();
+        let parent = dir.parent();
+        let crosses_filesystem = parent.is_some_and(|p| device_id(p) != start_device);
+        match parent {
+            Some(parent) if !at_git_root && !crosses_filesystem => dir = parent,
+            _ => {
+                return Err(ConfigError::ConfigNotDiscovered {
+                    start: start.clone(),
+                    stopped_at: dir.to_path_buf(),
+                })
+            }
+        }
+    }
+}
+
+/// Make `path` absolute so that walking up its parents works for `"."`.
+fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    std::env::current_dir()
+        .map(|cwd| cwd.join(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Device of the filesystem holding `path`, used to detect mount points.
+#[cfg(unix)]
+fn device_id(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    std::fs::metadata(path).ok().map(|m| m.dev())
+}
+
+/// Filesystem boundaries are only detected on Unix.
+#[cfg(not(unix))]
+fn device_id(_path: &Path) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const MINIMAL: &str = "project_name: \"demo\"\noutput_folder: \"./output\"\n";
+
+    #[test]
+    fn test_finds_config_in_parent_directory() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("config.yaml"), MINIMAL).unwrap();
+        let nested = root.path().join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+
+        let found = find_config_with(&nested, None).unwrap();
+        assert_eq!(found, root.path().join("config.yaml"));
+    }
+
+    #[test]
+    fn test_file_names_are_checked_in_priority_order() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join(".tf")).unwrap();
+        fs::write(root.path().join(".tf").join("config.yaml"), MINIMAL).unwrap();
+        fs::write(root.path().join("tf.yaml"), MINIMAL).unwrap();
+
+        let found = find_config_with(root.path(), None).unwrap();
+        assert_eq!(found, root.path().join(".tf").join("config.yaml"));
+
+        fs::write(root.path().join("config.yaml"), MINIMAL).unwrap();
+        let found = find_config_with(root.path(), None).unwrap();
+        assert_eq!(found, root.path().join("config.yaml"));
+    }
+
+    #[test]
+    fn test_search_stops_at_git_root() {
+        let outer = tempfile::tempdir().unwrap();
+        fs::write(outer.path().join("config.yaml"), MINIMAL).unwrap();
+        let repo = outer.path().join("repo");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        let nested = repo.join("src");
+        fs::create_dir(&nested).unwrap();
+
+        let err = find_config_with(&nested, None).unwrap_err();
+        match &err {
+            ConfigError::ConfigNotDiscovered { start, stopped_at } => {
+                assert_eq!(start, &nested);
+                assert_eq!(stopped_at, &repo);
+            }
+            other => panic!("expected ConfigNotDiscovered, got {:?}", other),
+        }
+        assert!(err.to_string().contains("TF_CONFIG"));
+    }
+
+    #[test]
+    fn test_env_var_takes_precedence() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("config.yaml"), MINIMAL).unwrap();
+        fs::write(root.path().join("ci.yaml"), MINIMAL).unwrap();
+
+        let found = find_config_with(root.path(), Some("ci.yaml".into())).unwrap();
+        assert_eq!(found, root.path().join("ci.yaml"));
+
+        let err = find_config_with(root.path(), Some("missing.yaml".into())).unwrap_err();
+        assert!(matches!(err, ConfigError::FileNotFound { .. }));
+
+        // An empty value is ignored
+        let found = find_config_with(root.path(), Some("".into())).unwrap();
+        assert_eq!(found, root.path().join("config.yaml"));
+    }
+
+    #[test]
+    fn test_discover_config_loads_found_file() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join(".git")).unwrap();
+        fs::write(
+            root.path().join("tf.yaml"),
+            format!(
+                "{}default_profile: ci\nprofiles:\n  ci:\n    output_folder: \"./ci-output\"\n",
+                MINIMAL
+            ),
+        )
+        .unwrap();
+        let nested = root.path().join("src");
+        fs::create_dir(&nested).unwrap();
+
+        let (path, config) = discover_config_with(&nested, None, None, Vec::new()).unwrap();
+        assert_eq!(path, root.path().join("tf.yaml"));
+        assert_eq!(config.project_name, "demo");
+        assert_eq!(config.base_dir.as_deref(), Some(root.path()));
+        assert!(config.output_folder.ends_with("ci-output"));
+
+        // TF_CONFIG and TF_PROFILE take precedence over the search and default_profile
+        fs::write(
+            nested.join("other.yaml"),
+            format!(
+                "{}profiles:\n  dev:\n    output_folder: \"./dev-output\"\n",
+                MINIMAL
+            ),
+        )
+        .unwrap();
+        let (path, config) = discover_config_with(
+            &nested,
+            Some("other.yaml".into()),
+            Some("dev".into()),
+            vec![("TF__PROJECT_NAME".to_string(), "from-env".to_string())],
+        )
+        .unwrap();
+        assert_eq!(path, nested.join("other.yaml"));
+        assert!(config.output_folder.ends_with("dev-output"));
+        assert_eq!(config.project_name, "from-env");
+    }
+}
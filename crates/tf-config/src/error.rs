@@ -8,7 +8,10 @@
 //! aligns better with Rust conventions (e.g., `ParseError`, `IoError`) where variant names
 //! describe the specific failure mode.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::format::ConfigFormat;
+use crate::location::{format_location, SourceLocation};
 
 /// Formats the available profiles list for user-friendly error messages.
 ///
@@ -22,6 +25,24 @@ fn format_available_profiles(available: &[String]) -> String {
     }
 }
 
+/// Formats the available Jira scopes list, like [`format_available_profiles`].
+fn format_available_scopes(available: &[String]) -> String {
+    if available.is_empty() {
+        "No scopes defined in configuration. Add a 'scopes' map under 'jira'.".to_string()
+    } else {
+        format!("Available scopes: {}", available.join(", "))
+    }
+}
+
+/// Formats a list of validation errors as a numbered checklist.
+fn format_error_list(errors: &[ConfigError]) -> String {
+    errors
+        .iter()
+        .enumerate()
+        .map(|(i, e)| format!("\n  {}. {}", i + 1, e))
+        .collect()
+}
+
 /// Errors that can occur when loading or validating configuration
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
@@ -29,17 +50,43 @@ pub enum ConfigError {
     #[error("Configuration file not found: {path}")]
     FileNotFound { path: PathBuf },
 
+    /// No configuration file was found by [`discover_config`](crate::discover_config).
+    #[error(
+        "No configuration file found in '{}' or its parents up to '{}'. Expected one of: config.yaml, .tf/config.yaml, tf.yaml (or set TF_CONFIG to the file path)",
+        start.display(),
+        stopped_at.display()
+    )]
+    ConfigNotDiscovered {
+        /// Directory the search started from
+        start: PathBuf,
+        /// Last directory searched (git root, filesystem boundary or root)
+        stopped_at: PathBuf,
+    },
+
     /// A required field is missing from the configuration
-    #[error("Invalid configuration: field '{field}' is missing. Expected: {hint}")]
-    MissingField { field: String, hint: String },
+    #[error(
+        "Invalid configuration: field '{field}' is missing. Expected: {hint}{}",
+        format_location(location)
+    )]
+    MissingField {
+        field: String,
+        hint: String,
+        /// Position in the configuration file, when loaded from a file
+        location: Option<Box<SourceLocation>>,
+    },
 
     /// A field has an invalid value (named `InvalidValue` instead of `ValidationError`
     /// for semantic precision - see module-level documentation)
-    #[error("Invalid configuration: field '{field}' {reason}. Expected: {hint}")]
+    #[error(
+        "Invalid configuration: field '{field}' {reason}. Expected: {hint}{}",
+        format_location(location)
+    )]
     InvalidValue {
         field: String,
         reason: String,
         hint: String,
+        /// Position in the configuration file, when loaded from a file
+        location: Option<Box<SourceLocation>>,
     },
 
     /// Requested profile does not exist in the configuration.
@@ -59,6 +106,84 @@ pub enum ConfigError {
         available: Vec<String>,
     },
 
+    /// Profile inheritance through `extends` loops back on itself.
+    ///
+    /// The chain lists the profiles in the order they were followed, ending with
+    /// the profile that closes the cycle (e.g. `preprod -> staging -> preprod`).
+    #[error(
+        "Profile inheritance cycle detected: {}. Remove one of the 'extends' keys to break the cycle.",
+        chain.join(" -> ")
+    )]
+    ProfileCycle {
+        /// Profiles followed through `extends`, the first one repeated at the end
+        chain: Vec<String>,
+    },
+
+    /// Requested Jira scope is not defined in `jira.scopes`.
+    ///
+    /// Returned by [`JiraConfig::scope`](crate::JiraConfig::scope); the error
+    /// lists the defined scopes so the user can pick a valid name.
+    #[error(
+        "Jira scope '{requested}' not found. {}",
+        format_available_scopes(available)
+    )]
+    ScopeNotFound {
+        /// The scope name that was requested
+        requested: String,
+        /// Names of the scopes defined under `jira.scopes`
+        available: Vec<String>,
+    },
+
+    /// A `${SECRET:key}` reference could not be resolved from the secret store.
+    ///
+    /// The message names the configuration field and the secret key, never the
+    /// secret value, and carries the actionable hint provided by the secret backend
+    /// (e.g. "Use 'tf secret set jira-token' to store this secret.").
+    #[error(
+        "Failed to resolve secret '{key}' for field '{field}': {reason}. {hint}{}",
+        format_location(location)
+    )]
+    SecretResolution {
+        /// Dotted path of the configuration field holding the reference
+        field: String,
+        /// Secret key referenced by `${SECRET:key}`
+        key: String,
+        /// Why the lookup failed (without any secret value)
+        reason: String,
+        /// Actionable hint to fix the problem
+        hint: String,
+        /// Position in the configuration file, when loaded from a file
+        location: Option<Box<SourceLocation>>,
+    },
+
+    /// An environment variable referenced with `${VAR}` or `${VAR:?message}` is not set.
+    #[error(
+        "Invalid configuration: field '{field}' references environment variable '{variable}' which is not set. Hint: {hint}{}",
+        format_location(location)
+    )]
+    MissingEnvVar {
+        /// Dotted path of the configuration field holding the reference
+        field: String,
+        /// Name of the missing environment variable
+        variable: String,
+        /// The `:?` message from the reference, or a generic hint
+        hint: String,
+        /// Position in the configuration file, when loaded from a file
+        location: Option<Box<SourceLocation>>,
+    },
+
+    /// Several validation problems were found at once.
+    ///
+    /// Returned by [`load_config_report`](crate::load_config_report) so that every
+    /// `MissingField`/`InvalidValue` problem can be displayed in a single pass.
+    /// Use [`ConfigError::into_errors`] to iterate over the individual errors.
+    #[error(
+        "Invalid configuration: {} problems found:{}",
+        .0.len(),
+        format_error_list(.0)
+    )]
+    Multiple(Vec<ConfigError>),
+
     /// Failed to read the configuration file.
     ///
     /// Note: This variant is tested implicitly through the `From<std::io::Error>` derive.
@@ -72,6 +197,29 @@ pub enum ConfigError {
     /// Failed to parse the YAML configuration
     #[error("Failed to parse configuration: {0}")]
     ParseError(#[from] serde_yaml::Error),
+
+    /// Failed to parse a TOML or JSON configuration (YAML uses `ParseError`)
+    #[error(
+        "Failed to parse {format} configuration: {message}{}",
+        format_location(location)
+    )]
+    SyntaxError {
+        /// Format of the configuration file
+        format: ConfigFormat,
+        /// Parser message, without the source text
+        message: String,
+        /// Position reported by the parser
+        location: Option<Box<SourceLocation>>,
+    },
+
+    /// Error inside a fragment file pulled in with `include:`
+    #[error("In included file {path}: {source}")]
+    Include {
+        /// Path of the fragment file
+        path: PathBuf,
+        /// The error found in the fragment
+        source: Box<ConfigError>,
+    },
 }
 
 impl ConfigError {
@@ -80,6 +228,90 @@ impl ConfigError {
         ConfigError::MissingField {
             field: field.into(),
             hint: hint.into(),
+            location: None,
+        }
+    }
+
+    /// Dotted path of the configuration field this error is about, if any.
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            ConfigError::MissingField { field, .. }
+            | ConfigError::InvalidValue { field, .. }
+            | ConfigError::SecretResolution { field, .. }
+            | ConfigError::MissingEnvVar { field, .. } => Some(field),
+            ConfigError::Include { source, .. } => source.field(),
+            _ => None,
+        }
+    }
+
+    /// Position of the offending key in the configuration file, if known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            ConfigError::MissingField { location, .. }
+            | ConfigError::InvalidValue { location, .. }
+            | ConfigError::SecretResolution { location, .. }
+            | ConfigError::MissingEnvVar { location, .. }
+            | ConfigError::SyntaxError { location, .. } => location.as_deref(),
+            ConfigError::Include { source, .. } => source.location(),
+            _ => None,
+        }
+    }
+
+    /// Attach a source location to a field-scoped error or a syntax error.
+    ///
+    /// Other variants are returned unchanged.
+    pub fn with_location(mut self, source_location: SourceLocation) -> Self {
+        match &mut self {
+            ConfigError::MissingField { location, .. }
+            | ConfigError::InvalidValue { location, .. }
+            | ConfigError::SecretResolution { location, .. }
+            | ConfigError::MissingEnvVar { location, .. }
+            | ConfigError::SyntaxError { location, .. } => {
+                *location = Some(Box::new(source_location));
+            }
+            ConfigError::Include { source, .. } => {
+                let inner = std::mem::replace(source.as_mut(), ConfigError::Multiple(Vec::new()));
+                **source = inner.with_location(source_location);
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Name the fragment file in which this error was found, unless a nested
+    /// fragment is already named.
+    pub(crate) fn in_fragment(self, path: &Path) -> Self {
+        match self {
+            ConfigError::Include { .. } => self,
+            other => ConfigError::Include {
+                path: path.to_path_buf(),
+                source: Box::new(other),
+            },
+        }
+    }
+
+    /// Build an error from a list of validation errors.
+    ///
+    /// Returns `None` for an empty list, the error itself for a single error,
+    /// and [`ConfigError::Multiple`] otherwise.
+    pub fn from_errors(mut errors: Vec<ConfigError>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(ConfigError::Multiple(errors)),
+        }
+    }
+
+    /// Flatten this error into its individual errors.
+    ///
+    /// [`ConfigError::Multiple`] yields its contained errors (recursively);
+    /// any other variant yields itself.
+    pub fn into_errors(self) -> Vec<ConfigError> {
+        match self {
+            ConfigError::Multiple(errors) => {
+                errors.into_iter().flat_map(|e| e.into_errors()).collect()
+            }
+            other => vec![other],
         }
     }
 
@@ -93,6 +325,7 @@ impl ConfigError {
             field: field.into(),
             reason: reason.into(),
             hint: hint.into(),
+            location: None,
         }
     }
 }
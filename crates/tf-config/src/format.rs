@@ -0,0 +1,195 @@
+//! Configuration file formats.
+//!
+//! [`load_config`](crate::load_config) picks the parser from the file
+//! extension:
+//!
+//! | Extension         | Format |
+//! |-------------------|--------|
+//! | `.yaml`, `.yml`   | YAML   |
+//! | `.toml`           | TOML   |
+//! | `.json`           | JSON   |
+//!
+//! Any other extension (or none) is read as YAML, as before. Whatever the
+//! format, the document is deserialized into the same
+//! [`ProjectConfig`](crate::ProjectConfig) and goes through the same
+//! migrations, validation and friendly error messages; only the syntax differs.
+//!
+//! ```toml
+//! project_name = "my-project"
+//! output_folder = "./output"
+//!
+//! [jira]
+//! endpoint = "https://jira.example.com"
+//!
+//! [profiles.dev.llm]
+//! mode = "local"
+//! ```
+
+use std::fmt;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+
+use crate::error::ConfigError;
+use crate::location::SourceLocation;
+
+/// Syntax of a configuration file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigFormat {
+    /// YAML (`.yaml`, `.yml`, and the fallback for unknown extensions)
+    #[default]
+    Yaml,
+    /// TOML (`.toml`)
+    Toml,
+    /// JSON (`.json`)
+    Json,
+}
+
+impl ConfigFormat {
+    /// Format of the file at `path`, from its extension (case-insensitive).
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("toml") => ConfigFormat::Toml,
+            Some("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Yaml,
+        }
+    }
+
+    /// Human-readable name of the format (`YAML`, `TOML`, `JSON`).
+    pub fn name(self) -> &'static str {
+        match self {
+            ConfigFormat::Yaml => "YAML",
+            ConfigFormat::Toml => "TOML",
+            ConfigFormat::Json => "JSON",
+        }
+    }
+
+    /// Deserialize a document written in this format.
+    pub(crate) fn deserialize<T: DeserializeOwned>(self, content: &str) -> Result<T, FormatError> {
+        match self {
+            ConfigFormat::Yaml => serde_yaml::from_str(content).map_err(FormatError::Yaml),
+            ConfigFormat::Toml => toml::from_str(content).map_err(FormatError::Toml),
+            ConfigFormat::Json => serde_json::from_str(content).map_err(FormatError::Json),
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error reported by the parser of one of the formats.
+#[derive(Debug)]
+pub(crate) enum FormatError {
+    Yaml(serde_yaml::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+}
+
+impl FormatError {
+    /// The parser message, as matched by `parse_serde_error`.
+    ///
+    /// TOML and JSON messages are returned without their position (reported
+    /// separately by [`FormatError::position`]); the TOML rendering would
+    /// otherwise quote the source line unredacted.
+    pub(crate) fn message(&self) -> String {
+        match self {
+            FormatError::Yaml(e) => e.to_string(),
+            FormatError::Toml(e) => e.message().to_string(),
+            FormatError::Json(e) => {
+                let message = e.to_string();
+                let suffix = format!(" at line {} column {}", e.line(), e.column());
+                match message.strip_suffix(&suffix) {
+                    Some(stripped) => stripped.to_string(),
+                    None => message,
+                }
+            }
+        }
+    }
+
+    /// Error for a document that could not be parsed at all.
+    pub(crate) fn into_config_error(
+        self,
+        format: ConfigFormat,
+        location: Option<SourceLocation>,
+    ) -> ConfigError {
+        match self {
+            FormatError::Yaml(e) => ConfigError::ParseError(e),
+            other => ConfigError::SyntaxError {
+                format,
+                message: other.message(),
+                location: location.map(Box::new),
+            },
+        }
+    }
+
+    /// 1-based (line, column) of the error in `content`, when the parser knows it.
+    pub(crate) fn position(&self, content: &str) -> Option<(usize, usize)> {
+        match self {
+            FormatError::Yaml(e) => e.location().map(|loc| (loc.line(), loc.column())),
+            FormatError::Toml(e) => e.span().map(|span| line_column(content, span.start)),
+            FormatError::Json(e) => (e.line() > 0).then(|| (e.line(), e.column())),
+        }
+    }
+}
+
+/// 1-based (line, column) of a byte offset.
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("config.yaml")),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("tf.yml")),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("config.TOML")),
+            ConfigFormat::Toml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new(".tf/config.json")),
+            ConfigFormat::Json
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("config")),
+            ConfigFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn test_error_message_and_position_per_format() {
+        let err = ConfigFormat::Toml
+            .deserialize::<serde_json::Value>("a = 1\nb = = 2\n")
+            .unwrap_err();
+        assert_eq!(
+            err.position("a = 1\nb = = 2\n").map(|(line, _)| line),
+            Some(2)
+        );
+        assert!(!err.message().contains("b = = 2"));
+
+        let err = ConfigFormat::Json
+            .deserialize::<serde_json::Value>("{\n  \"a\": 1,\n}")
+            .unwrap_err();
+        assert_eq!(err.position(""), Some((3, 1)));
+        assert!(!err.message().contains("at line"));
+    }
+}
@@ -0,0 +1,239 @@
+//! Configuration fragments (`include:`).
+//!
+//! Sections shared by several projects can live in fragment files listed
+//! under `include`, relative to the file that includes them:
+//!
+//! ```yaml
+//! include:
+//!   - "../shared/jira.yaml"
+//!   - "./llm.yaml"
+//! project_name: "my-project"
+//! output_folder: "./output"
+//! ```
+//!
+//! Fragments are partial configurations in any supported format (see
+//! [`ConfigFormat`]). They are merged before profiles are resolved:
+//!
+//! - mappings are merged key by key; any other value replaces the one below it
+//! - a later fragment overrides an earlier one, and the including file
+//!   overrides all of its fragments
+//! - fragments can include other fragments; include cycles are rejected
+//!
+//! Errors on a value coming from a fragment point at the fragment file, and
+//! errors inside a fragment (missing file, syntax error) are reported as
+//! [`ConfigError::Include`] naming it.
+//!
+//! # Security
+//!
+//! The configuration file itself may include files anywhere, e.g. a shared
+//! directory next to the project. Fragments are often maintained elsewhere, so
+//! their own `include` entries are held to the `..` rule applied to
+//! `output_folder` and template paths, and must be relative: a fragment can
+//! only pull in files below its own directory.
+
+use std::path::{Path, PathBuf};
+
+use serde_yaml::{Mapping, Value};
+
+use crate::config::is_safe_path;
+use crate::error::ConfigError;
+use crate::format::ConfigFormat;
+use crate::location::ConfigSource;
+
+/// Top-level key listing the fragments to merge.
+pub const INCLUDE_KEY: &str = "include";
+
+/// Merge the fragments included by the document read from `path`.
+///
+/// Returns whether the document was changed; `source` gets the key index of
+/// every fragment, so that errors on merged values can be located.
+pub(crate) fn resolve_includes(
+    path: &Path,
+    document: &mut Value,
+    source: &mut ConfigSource,
+) -> Result<bool, ConfigError> {
+    let mut chain = vec![canonical(path)];
+    merge_includes(path, document, source, &mut chain)
+}
+
+/// Merge the fragments of one file; `chain` holds the files being included,
+/// outermost first, to detect cycles.
+fn merge_includes(
+    path: &Path,
+    document: &mut Value,
+    source: &mut ConfigSource,
+    chain: &mut Vec<PathBuf>,
+) -> Result<bool, ConfigError> {
+    let Value::Mapping(mapping) = document else {
+        return Ok(false);
+    };
+    let Some(include) = mapping.get(INCLUDE_KEY) else {
+        return Ok(false);
+    };
+    let entries = include_entries(include).map_err(|e| source.attach(e, &[]))?;
+    let is_fragment = chain.len() > 1;
+    let base_dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let mut merged = Value::Mapping(Mapping::new());
+    let mut fragments = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let field = format!("{}.{}", INCLUDE_KEY, index);
+        if is_fragment && (!is_safe_path(entry) || Path::new(entry).is_absolute()) {
+            return Err(source.attach(
+                ConfigError::invalid_value(
+                    field,
+                    "must stay below the directory of the fragment (no '..', no absolute path)",
+                    "a relative path like './jira.yaml'",
+                ),
+                &[],
+            ));
+        }
+
+        let fragment_path = base_dir.join(entry);
+        if !fragment_path.is_file() {
+            return Err(source.attach(
+                ConfigError::invalid_value(
+                    field,
+                    format!(
+                        "refers to '{}', which does not exist",
+                        fragment_path.display()
+                    ),
+                    "a path relative to the including file",
+                ),
+                &[],
+            ));
+        }
+        let fragment_canonical = canonical(&fragment_path);
+        if chain.contains(&fragment_canonical) {
+            let cycle: Vec<String> = chain
+                .iter()
+                .chain(std::iter::once(&fragment_canonical))
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err(source.attach(
+                ConfigError::invalid_value(
+                    field,
+                    format!("creates an include cycle: {}", cycle.join(" -> ")),
+                    "remove one of the includes",
+                ),
+                &[],
+            ));
+        }
+
+        chain.push(fragment_canonical);
+        let (fragment, fragment_source) =
+            load_fragment(&fragment_path, chain).map_err(|e| e.in_fragment(&fragment_path))?;
+        chain.pop();
+
+        merge_value(&mut merged, fragment);
+        fragments.push(fragment_source);
+    }
+
+    merge_value(&mut merged, std::mem::take(document));
+    *document = merged;
+    // Later fragments take precedence over earlier ones
+    for fragment_source in fragments.into_iter().rev() {
+        source.push_fragment(fragment_source);
+    }
+    Ok(true)
+}
+
+/// Read and parse a fragment, merging its own fragments.
+fn load_fragment(
+    path: &Path,
+    chain: &mut Vec<PathBuf>,
+) -> Result<(Value, ConfigSource), ConfigError> {
+    let content = std::fs::read_to_string(path)?;
+    let format = ConfigFormat::from_path(path);
+    let mut source = ConfigSource::new(path, &content);
+    let mut document: Value = format.deserialize(&content).map_err(|e| {
+        let location = e
+            .position(&content)
+            .and_then(|(line, column)| source.locate_position(line, column));
+        e.into_config_error(format, location)
+    })?;
+
+    merge_includes(path, &mut document, &mut source, chain)?;
+    if let Value::Mapping(mapping) = &mut document {
+        // The including file keeps its own list
+        mapping.remove(INCLUDE_KEY);
+    }
+    Ok((document, source))
+}
+
+/// The `include` entries, which must be a list of strings.
+pub(crate) fn include_entries(include: &Value) -> Result<Vec<String>, ConfigError> {
+    let invalid = || {
+        ConfigError::invalid_value(
+            INCLUDE_KEY,
+            "must be a list of file paths",
+            "include: [\"../shared/jira.yaml\"]",
+        )
+    };
+    match include {
+        Value::Sequence(items) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string).ok_or_else(invalid))
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+
+/// Merge `overlay` into `base`: mappings key by key, other values replaced.
+fn merge_value(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Mapping(base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_value(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Canonical form of a path for cycle detection, or the path itself when it
+/// cannot be resolved.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yaml(text: &str) -> Value {
+        serde_yaml::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_merge_value_is_deep_and_overlay_wins() {
+        let mut base = yaml("jira:\n  endpoint: a\n  token: t\ninclude: [x]\n");
+        merge_value(
+            &mut base,
+            yaml("jira:\n  endpoint: b\nllm:\n  mode: local\n"),
+        );
+        assert_eq!(
+            base,
+            yaml("jira:\n  endpoint: b\n  token: t\ninclude: [x]\nllm:\n  mode: local\n")
+        );
+    }
+
+    #[test]
+    fn test_include_must_be_a_list_of_strings() {
+        let err = include_entries(&yaml("shared.yaml")).unwrap_err();
+        assert_eq!(err.field(), Some(INCLUDE_KEY));
+        assert!(include_entries(&yaml("[a.yaml, 3]")).is_err());
+        assert_eq!(
+            include_entries(&yaml("[a.yaml, ./b.toml]")).unwrap(),
+            ["a.yaml", "./b.toml"]
+        );
+    }
+}
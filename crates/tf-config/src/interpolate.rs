@@ -0,0 +1,343 @@
+//! Environment-variable interpolation in configuration values.
+//!
+//! String values may reference environment variables using a shell-like syntax:
+//!
+//! | Syntax | Behavior |
+//! |--------|----------|
+//! | `${VAR}` | Value of `VAR`; error if `VAR` is not set |
+//! | `${VAR:-default}` | Value of `VAR`, or `default` if `VAR` is unset or empty |
+//! | `${VAR:?message}` | Value of `VAR`; error with `message` if `VAR` is unset or empty |
+//!
+//! Interpolation applies to every string field of [`ProjectConfig`], including
+//! profile overrides, and runs in [`load_config`](crate::load_config) before
+//! validation, so interpolated values are validated like literal ones.
+//!
+//! `${SECRET:key}` references are left untouched: they are resolved from the OS
+//! keyring by [`resolve_secrets`](crate::resolve_secrets).
+//!
+//! A few strings are deliberately not interpolated:
+//!
+//! - `anonymization.rules.*.pattern`: `$` is an end-of-line anchor in regular
+//!   expressions, so a pattern like `[0-9]{4}$` stays as written
+//! - `anonymization.rules.*.replacement`: `${1}` refers to a capture group of
+//!   the pattern, not to an environment variable
+//! - `include`: fragments are merged before the configuration is deserialized,
+//!   so their paths are read before interpolation runs
+//! - `default_profile` and profile `extends`: profile names, not values
+//!
+//! # Example
+//!
+//! ```yaml
+//! jira:
+//!   endpoint: "${JIRA_URL:-https://jira.example.com}"
+//!   token: "${JIRA_TOKEN:?export JIRA_TOKEN before running tf}"
+//! ```
+//!
+//! Default values are taken literally: nested references such as
+//! `${A:-${B}}` are not expanded.
+
+use crate::config::ProjectConfig;
+use crate::error::ConfigError;
+
+/// Replace `${VAR}`, `${VAR:-default}` and `${VAR:?message}` references in every
+/// string field of `config` using the process environment.
+///
+/// # Errors
+///
+/// - [`ConfigError::MissingEnvVar`] if a referenced variable is not set (or is
+///   empty for the `:?` form); the error names the field and the variable
+/// - [`ConfigError::InvalidValue`] if a reference is malformed
+pub fn interpolate_env_vars(config: &mut ProjectConfig) -> Result<(), ConfigError> {
+    interpolate_with(config, |name| std::env::var(name).ok())
+}
+
+/// Same as [`interpolate_env_vars`], with a custom variable lookup.
+///
+/// Useful to interpolate from a map instead of the process environment
+/// (e.g. in tests or when variables come from a `.env` file).
+///
+/// # Example
+///
+/// ```
+/// use tf_config::{interpolate_with, ProjectConfig};
+///
+/// let mut config: ProjectConfig = serde_yaml::from_str(
+///     "project_name: \"demo\"\noutput_folder: \"${OUT:-./output}\"\n",
+/// )
+/// .unwrap();
+/// interpolate_with(&mut config, |_| None).unwrap();
+/// assert_eq!(config.output_folder, "./output");
+/// ```
+pub fn interpolate_with<F>(config: &mut ProjectConfig, lookup: F) -> Result<(), ConfigError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    config.try_for_each_string_mut(&mut |field, value| {
+        if value.contains("${") {
+            *value = interpolate_value(field, value, &lookup)?;
+        }
+        Ok(())
+    })
+}
+
+/// Expand all references inside a single value.
+fn interpolate_value<F>(field: &str, value: &str, lookup: &F) -> Result<String, ConfigError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after_open = &rest[start + 2..];
+        let Some(end) = after_open.find('}') else {
+            return Err(ConfigError::invalid_value(
+                field,
+                "contains an unterminated variable reference",
+                "a reference of the form ${VAR}, ${VAR:-default} or ${VAR:?message}",
+            ));
+        };
+        let expr = &after_open[..end];
+        rest = &after_open[end + 1..];
+
+        // Secret references are resolved later from the keyring, keep them verbatim
+        if is_secret_expression(expr) {
+            result.push_str("${");
+            result.push_str(expr);
+            result.push('}');
+            continue;
+        }
+
+        let (name, modifier) = match expr.find(':') {
+            Some(pos) => (&expr[..pos], Some(&expr[pos..])),
+            None => (expr, None),
+        };
+        if !is_valid_var_name(name) {
+            return Err(ConfigError::invalid_value(
+                field,
+                format!("contains an invalid environment variable name '{}'", name),
+                "a name made of letters, digits and '_' not starting with a digit (e.g., ${JIRA_TOKEN})",
+            ));
+        }
+
+        let current = lookup(name);
+        let expanded = match modifier {
+            None => current.ok_or_else(|| ConfigError::MissingEnvVar {
+                field: field.to_string(),
+                variable: name.to_string(),
+                hint: format!(
+                    "export {} or use ${{{}:-default}} to provide a fallback",
+                    name, name
+                ),
+                location: None,
+            })?,
+            Some(m) if m.starts_with(":-") => match current {
+                Some(v) if !v.is_empty() => v,
+                _ => m[2..].to_string(),
+            },
+            Some(m) if m.starts_with(":?") => match current {
+                Some(v) if !v.is_empty() => v,
+                _ => {
+                    let message = m[2..].trim();
+                    return Err(ConfigError::MissingEnvVar {
+                        field: field.to_string(),
+                        variable: name.to_string(),
+                        hint: if message.is_empty() {
+                            format!("export {} with a non-empty value", name)
+                        } else {
+                            message.to_string()
+                        },
+                        location: None,
+                    });
+                }
+            },
+            Some(_) => {
+                return Err(ConfigError::invalid_value(
+                    field,
+                    format!("contains an unsupported modifier for variable '{}'", name),
+                    "${VAR}, ${VAR:-default} or ${VAR:?message}",
+                ));
+            }
+        };
+        result.push_str(&expanded);
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// `SECRET:key` (but not `SECRET:-default` / `SECRET:?message`, which target a
+/// variable named `SECRET`).
+fn is_secret_expression(expr: &str) -> bool {
+    expr.strip_prefix("SECRET:")
+        .is_some_and(|rest| !rest.starts_with('-') && !rest.starts_with('?'))
+}
+
+/// POSIX-style variable names: `[A-Za-z_][A-Za-z0-9_]*`.
+fn is_valid_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn parse(yaml: &str) -> ProjectConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| map.get(name).cloned()
+    }
+
+    #[test]
+    fn test_plain_default_and_required_forms() {
+        let mut config = parse(
+            r#"
+project_name: "demo"
+output_folder: "${OUT_DIR:-./output}"
+jira:
+  endpoint: "https://${JIRA_HOST}/jira"
+  token: "${JIRA_TOKEN:?set JIRA_TOKEN}"
+"#,
+        );
+
+        interpolate_with(
+            &mut config,
+            env(&[("JIRA_HOST", "jira.example.com"), ("JIRA_TOKEN", "t0k")]),
+        )
+        .unwrap();
+
+        assert_eq!(config.output_folder, "./output");
+        let jira = config.jira.unwrap();
+        assert_eq!(jira.endpoint, "https://jira.example.com/jira");
+        assert_eq!(jira.token.as_deref(), Some("t0k"));
+    }
+
+    #[test]
+    fn test_default_applies_to_empty_value() {
+        let mut config = parse("project_name: \"demo\"\noutput_folder: \"${OUT:-./fallback}\"\n");
+        interpolate_with(&mut config, env(&[("OUT", "")])).unwrap();
+        assert_eq!(config.output_folder, "./fallback");
+    }
+
+    #[test]
+    fn test_unset_variable_error_names_field_and_variable() {
+        let mut config = parse(
+            r#"
+project_name: "demo"
+output_folder: "./output"
+profiles:
+  dev:
+    jira:
+      endpoint: "https://jira.dev.example.com"
+      token: "${JIRA_TOKEN}"
+"#,
+        );
+
+        let err = interpolate_with(&mut config, env(&[])).unwrap_err();
+        match &err {
+            ConfigError::MissingEnvVar {
+                field, variable, ..
+            } => {
+                assert_eq!(field, "profiles.dev.jira.token");
+                assert_eq!(variable, "JIRA_TOKEN");
+            }
+            other => panic!("expected MissingEnvVar, got {:?}", other),
+        }
+        assert!(err.to_string().contains("JIRA_TOKEN"));
+    }
+
+    #[test]
+    fn test_required_form_uses_custom_message() {
+        let mut config =
+            parse("project_name: \"demo\"\noutput_folder: \"${OUT:?run from the CI wrapper}\"\n");
+        let err = interpolate_with(&mut config, env(&[("OUT", "")])).unwrap_err();
+        assert!(err.to_string().contains("run from the CI wrapper"));
+    }
+
+    #[test]
+    fn test_secret_references_are_preserved() {
+        let mut config = parse(
+            r#"
+project_name: "demo"
+output_folder: "./output"
+jira:
+  endpoint: "https://jira.example.com"
+  token: "${SECRET:jira-token}"
+"#,
+        );
+        interpolate_with(&mut config, env(&[])).unwrap();
+        assert_eq!(
+            config.jira.unwrap().token.as_deref(),
+            Some("${SECRET:jira-token}")
+        );
+    }
+
+    #[test]
+    fn test_checklist_and_anonymization_values_are_interpolated() {
+        let mut config = parse(
+            r#"
+project_name: "demo"
+output_folder: "./output"
+checklist:
+  criteria:
+    - id: ac
+      label: "${AC_LABEL}"
+      weight: 100
+anonymization:
+  rules:
+    - name: invoice
+      pattern: "INV-([0-9]+)$"
+      replacement: "[INVOICE-${1}]"
+  allow:
+    reporter: ["${BOT_EMAIL}"]
+"#,
+        );
+        interpolate_with(
+            &mut config,
+            env(&[
+                ("AC_LABEL", "Acceptance criteria"),
+                ("BOT_EMAIL", "qa@x.io"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.checklist.unwrap().criteria[0].label,
+            "Acceptance criteria"
+        );
+        let anonymization = config.anonymization.unwrap();
+        assert_eq!(anonymization.rules[0].pattern, "INV-([0-9]+)$");
+        assert_eq!(anonymization.rules[0].replacement(), "[INVOICE-${1}]");
+        assert!(anonymization.is_allowed("reporter", "qa@x.io"));
+    }
+
+    #[test]
+    fn test_malformed_references_are_rejected() {
+        for value in ["${OUT", "${1BAD}", "${OUT:+x}", "${}"] {
+            let mut config = parse(&format!(
+                "project_name: \"demo\"\noutput_folder: \"{}\"\n",
+                value
+            ));
+            let err = interpolate_with(&mut config, env(&[("OUT", "x")])).unwrap_err();
+            assert!(
+                matches!(&err, ConfigError::InvalidValue { field, .. } if field == "output_folder"),
+                "unexpected error for {:?}: {:?}",
+                value,
+                err
+            );
+        }
+    }
+}
@@ -6,8 +6,31 @@
 //! # Features
 //!
 //! - **YAML Configuration Loading**: Load project configuration from YAML files with schema validation
+//! - **TOML and JSON Files**: `.toml` and `.json` files are loaded into the same schema, with the same validation and error messages (see [`format`](mod@format))
+//! - **Versioned Schema**: `config_version` with step-by-step migrations of older files, reported and optionally rewritten in place (see [`migrate`])
+//! - **Config Fragments**: `include:` merges shared fragment files (e.g. a common Jira section) before profiles, with cycle and path-traversal checks (see [`include`](mod@include))
+//! - **Config Discovery**: Find `config.yaml` by walking up from the working directory, or from `TF_CONFIG` (see [`discover`])
 //! - **Explicit Error Messages**: Validation errors include field name, reason, and correction hints
+//! - **Error Locations**: Errors point at the file, line and column of the offending key with a snippet (see [`location`])
+//! - **YAML Export**: Write the effective configuration back to YAML with secrets masked, e.g. for bug reports (see [`export`])
+//! - **Config Diff**: Changed leaf fields between two configurations or two profiles, redacted, as text or JSON (see [`diff`])
+//! - **Lint Warnings**: Coded, non-fatal warnings for plaintext secrets, `http://` endpoints, redundant profiles and missing files (see [`lint`])
 //! - **Sensitive Data Protection**: Secrets (tokens, passwords, API keys) are automatically redacted in logs
+//! - **Environment Interpolation**: `${VAR}`, `${VAR:-default}` and `${VAR:?message}` are expanded at load time (see [`interpolate`])
+//! - **Value Overrides**: Override any key from `TF__SECTION__FIELD` variables or `ProjectConfig::set_override` (see [`overrides`])
+//! - **Value Provenance**: Every effective value records whether it came from the file, a default, a profile, an env override or code (see [`provenance`])
+//! - **Secret References**: `${SECRET:key}` values are resolved from the OS keyring (see [`secrets`])
+//! - **Profile Merge Strategies**: Profile sections replace the base section or, with `merge: deep`, override only the fields they set (see [`profiles`])
+//! - **Profile Selection**: `load_config_with_profile` applies an explicit profile, else `TF_PROFILE`, else the file's `default_profile`, and reports which one won
+//! - **Profile Inheritance**: Profiles can `extends` another profile; chains are resolved in order and cycles rejected (see [`profiles`])
+//! - **Config Scaffolding**: Generate a commented, validated `config.yaml` from `config init` answers, with `${SECRET:key}` placeholders only (see [`scaffold`])
+//! - **JSON Schema**: Generate a JSON Schema for `config.yaml` from the config structs for editor autocomplete (see [`schema`])
+//! - **Hot Reload**: `ConfigWatcher` reloads the configuration, its fragments and templates on change, keeping the last good configuration on error (see [`watch`])
+//! - **Network Settings**: Shared proxy, `no_proxy`, CA bundle, client certificate and timeouts, overridable per integration (see [`network`])
+//! - **Jira Scopes**: Named import perimeters (project keys, components, fix versions, `last_7d`-style windows, JQL) selectable by name, and custom field mappings (see [`scopes`])
+//! - **Resilience Policy**: Per-integration retries with exponential backoff, retryable statuses and a `fail`/`csv`/`cache` fallback, with defaults (see [`resilience`])
+//! - **Testability Checklist**: Weighted criteria, blocking flags and Go/Clarify/No-Go thresholds, validated and overridable per profile (see [`checklist`])
+//! - **Anonymization Policy**: Entity types, custom regex rules and per-field allow-lists, with a strict mode that blocks cloud LLM use until a policy is set (see [`anonymization`])
 //! - **Flexible Schema**: Support for Jira, Squash, templates, and LLM integrations (all optional)
 //!
 //! # Quick Start
@@ -56,19 +79,109 @@
 //! }
 //! ```
 
+pub mod anonymization;
+pub mod checklist;
 pub mod config;
+pub mod diff;
+pub mod discover;
 pub mod error;
+pub mod export;
+pub mod format;
+pub mod include;
+pub mod interpolate;
+pub mod lint;
+pub mod location;
+pub mod migrate;
+pub mod network;
+pub mod overrides;
 pub mod profiles;
+pub mod provenance;
+pub mod resilience;
+pub mod scaffold;
+pub mod schema;
+pub mod scopes;
+pub mod secrets;
 pub mod template;
+pub mod watch;
 
 pub use config::{
-    load_config, redact_url_sensitive_params, JiraConfig, LlmConfig, LlmMode, ProjectConfig,
-    Redact, SquashConfig, TemplatesConfig,
+    load_config, load_config_report, load_config_with_profile, redact_url_sensitive_params,
+    JiraConfig, LlmConfig, LlmMode, ProjectConfig, Redact, SquashConfig, TemplatesConfig,
+    PROFILE_ENV_VAR,
 };
 pub use error::ConfigError;
 
+// File formats selected by extension (YAML, TOML, JSON)
+pub use format::ConfigFormat;
+
+// Fragment files merged with `include:`
+pub use include::INCLUDE_KEY;
+
+// Schema versioning and migrations
+pub use migrate::{
+    migrate_config_file, migrate_yaml, plan_migration, MigrationChange, MigrationReport,
+    CONFIG_VERSION_KEY, CURRENT_CONFIG_VERSION,
+};
+
+// Configuration file discovery (`config.yaml`, `.tf/config.yaml`, `tf.yaml`, `TF_CONFIG`)
+pub use discover::{discover_config, find_config, CONFIG_ENV_VAR, CONFIG_FILE_NAMES};
+pub use location::{ConfigSource, SourceLocation};
+
 // Profile types for Story 0.2
-pub use profiles::{ProfileId, ProfileOverride};
+pub use profiles::{
+    ChecklistOverride, JiraOverride, LlmOverride, MergeStrategy, ProfileId, ProfileOverride,
+    SquashOverride, TemplatesOverride, ThresholdsOverride,
+};
+
+// Proxy, CA bundle and TLS settings
+pub use network::{Integration, NetworkConfig, DEFAULT_CONNECT_TIMEOUT, DEFAULT_READ_TIMEOUT};
+
+// Retry and degraded-mode policy of the integrations
+pub use resilience::{FallbackMode, ResilienceConfig};
+
+// Named Jira import scopes and custom field mappings
+pub use scopes::{JiraScope, RelativeWindow, WindowUnit};
+
+// Anonymization policy for data sent to LLMs
+pub use anonymization::{AnonymizationConfig, AnonymizationRule, EntityType};
+
+// Testability checklist and Go/Clarify/No-Go scoring rules
+pub use checklist::{ChecklistConfig, ChecklistCriterion, Decision, ScoreThresholds, TOTAL_WEIGHT};
+
+// Differences between configurations or profiles
+pub use diff::{ChangeKind, ConfigDiff, FieldChange};
+
+// Non-fatal lint warnings
+pub use lint::{lint_config, ConfigWarning, WarningCode};
+
+// Environment-variable interpolation (`${VAR}`, `${VAR:-default}`, `${VAR:?message}`)
+pub use interpolate::{interpolate_env_vars, interpolate_with};
+
+// Single-value overrides (`TF__SECTION__FIELD`, `set_override`)
+pub use overrides::{overridable_keys, ENV_OVERRIDE_PREFIX};
+
+// Value provenance (file, default, profile, env, override)
+pub use provenance::{Provenance, ValueSource};
+
+// Writing the effective configuration back to YAML
+pub use export::REDACTED_PLACEHOLDER;
+
+// `config.yaml` generation from `config init` answers
+pub use scaffold::{
+    ConfigScaffold, ScaffoldProfile, JIRA_TOKEN_SECRET, LLM_API_KEY_SECRET, SQUASH_PASSWORD_SECRET,
+};
+
+// JSON Schema for `config.yaml`
+pub use schema::{config_schema, config_schema_json, SCHEMA_TITLE};
+
+// Secret reference resolution (`${SECRET:key}`)
+pub use secrets::{
+    is_secret_reference, load_config_with_secrets, resolve_secrets, SecretLookupError,
+    SecretResolver,
+};
 
 // Template types for Story 0.4
 pub use template::{validate_content, LoadedTemplate, TemplateError, TemplateKind, TemplateLoader};
+
+// Hot reload of the configuration and its templates
+pub use watch::{ConfigEvent, ConfigWatcher, LoadedConfig, ReloadError, DEFAULT_POLL_INTERVAL};
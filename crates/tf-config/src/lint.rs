@@ -0,0 +1,436 @@
+//! Non-fatal configuration checks.
+//!
+//! [`lint_config`] reports problems that do not prevent loading but are likely
+//! mistakes or security risks. Each [`ConfigWarning`] carries a stable
+//! [`WarningCode`], the field it is about and, for configurations loaded from a
+//! file, its position:
+//!
+//! | Code | Name | Checks |
+//! |------|------|--------|
+//! | W001 | `plaintext-secret` | `token`/`password`/`api_key` not using `${SECRET:key}` or `${VAR}` |
+//! | W002 | `insecure-endpoint` | `http://` endpoint for Jira, Squash or the cloud LLM |
+//! | W003 | `profile-identical-to-base` | profile whose overrides change nothing |
+//! | W004 | `unused-profile` | empty profile, neither active nor extended |
+//! | W005 | `single-label-host` | endpoint host without a domain (e.g. `https://jira`) |
+//! | W006 | `missing-template` | template file configured but not found on disk |
+//! | W007 | `output-folder` | `output_folder` missing or not a directory |
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::{lint_config, load_config};
+//!
+//! let config = load_config(Path::new("config.yaml"))?;
+//! for warning in lint_config(&config) {
+//!     eprintln!("{}", warning);
+//! }
+//! # Ok::<(), tf_config::ConfigError>(())
+//! ```
+
+use std::fmt;
+
+use crate::config::{ProjectConfig, TemplatesConfig};
+use crate::export::is_secret_field;
+use crate::location::{format_location, SourceLocation};
+use crate::secrets::is_secret_reference;
+
+/// Stable identifier of a lint check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningCode {
+    /// A secret field holds a literal value instead of a reference
+    PlaintextSecret,
+    /// Jira, Squash or the cloud LLM is reached over plain HTTP
+    InsecureEndpoint,
+    /// Applying the profile gives the base configuration back
+    ProfileIdenticalToBase,
+    /// The profile sets nothing and nothing refers to it
+    UnusedProfile,
+    /// An endpoint host has no domain part
+    SingleLabelHost,
+    /// A configured template file does not exist
+    MissingTemplate,
+    /// The output folder does not exist or is not a directory
+    OutputFolder,
+}
+
+impl WarningCode {
+    /// Short code, e.g. `W001`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            WarningCode::PlaintextSecret => "W001",
+            WarningCode::InsecureEndpoint => "W002",
+            WarningCode::ProfileIdenticalToBase => "W003",
+            WarningCode::UnusedProfile => "W004",
+            WarningCode::SingleLabelHost => "W005",
+            WarningCode::MissingTemplate => "W006",
+            WarningCode::OutputFolder => "W007",
+        }
+    }
+
+    /// Readable name, e.g. `plaintext-secret`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            WarningCode::PlaintextSecret => "plaintext-secret",
+            WarningCode::InsecureEndpoint => "insecure-endpoint",
+            WarningCode::ProfileIdenticalToBase => "profile-identical-to-base",
+            WarningCode::UnusedProfile => "unused-profile",
+            WarningCode::SingleLabelHost => "single-label-host",
+            WarningCode::MissingTemplate => "missing-template",
+            WarningCode::OutputFolder => "output-folder",
+        }
+    }
+}
+
+impl fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// A non-fatal problem found by [`lint_config`].
+///
+/// Messages name fields, never secret values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    /// Which check raised the warning
+    pub code: WarningCode,
+    /// Dotted path of the field (e.g. `profiles.dev.jira.token`)
+    pub field: String,
+    /// What is wrong
+    pub message: String,
+    /// How to fix it
+    pub hint: String,
+    /// Position in the configuration file, when loaded from a file
+    pub location: Option<Box<SourceLocation>>,
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "warning[{}/{}]: field '{}' {}. Hint: {}{}",
+            self.code.code(),
+            self.code.name(),
+            self.field,
+            self.message,
+            self.hint,
+            format_location(&self.location)
+        )
+    }
+}
+
+/// Run every lint check on `config`.
+///
+/// Warnings are ordered by check, then by field. Profiles are checked as
+/// written in the file, and also applied to detect identical profiles.
+pub fn lint_config(config: &ProjectConfig) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+    lint_strings(config, &mut warnings);
+    lint_profiles(config, &mut warnings);
+    lint_templates(config, &mut warnings);
+    if let Some(message) = config.check_output_folder_exists() {
+        let message = message
+            .strip_prefix(&format!("output_folder '{}' ", config.output_folder))
+            .unwrap_or(&message)
+            .to_string();
+        warnings.push(warning(
+            config,
+            WarningCode::OutputFolder,
+            "output_folder".to_string(),
+            message,
+            "create the folder, or point output_folder to an existing directory",
+        ));
+    }
+    warnings.sort_by(|a, b| (a.code.code(), &a.field).cmp(&(b.code.code(), &b.field)));
+    warnings
+}
+
+/// Secret and endpoint checks, on the base configuration and every profile.
+fn lint_strings(config: &ProjectConfig, warnings: &mut Vec<ConfigWarning>) {
+    let mut fields = Vec::new();
+    let mut copy = config.clone();
+    copy.try_for_each_string_mut(&mut |field, value| {
+        fields.push((field.to_string(), value.clone()));
+        Ok(())
+    })
+    .expect("the collecting visitor never fails");
+
+    for (field, value) in fields {
+        let name = field.rsplit('.').next().unwrap_or(&field);
+        if is_secret_field(&field) && !value.is_empty() && !is_reference(config, &field, &value) {
+            warnings.push(warning(
+                config,
+                WarningCode::PlaintextSecret,
+                field,
+                "holds a plaintext secret".to_string(),
+                "store it with 'tf secret set <key>' and use \"${SECRET:<key>}\"",
+            ));
+            continue;
+        }
+        if !name.ends_with("endpoint") {
+            continue;
+        }
+        let remote = ["jira.endpoint", "squash.endpoint", "llm.cloud_endpoint"];
+        if remote.iter().any(|r| field.ends_with(r))
+            && value.to_ascii_lowercase().starts_with("http://")
+        {
+            warnings.push(warning(
+                config,
+                WarningCode::InsecureEndpoint,
+                field.clone(),
+                "uses http://, so credentials and data are sent unencrypted".to_string(),
+                "use https://",
+            ));
+        }
+        if let Some(host) = endpoint_host(&value) {
+            if is_single_label(host) {
+                warnings.push(warning(
+                    config,
+                    WarningCode::SingleLabelHost,
+                    field,
+                    format!("uses host '{}' which has no domain", host),
+                    "use the fully qualified host name (e.g. jira.example.com) so it resolves outside the local network",
+                ));
+            }
+        }
+    }
+}
+
+/// Identical and unused profiles.
+fn lint_profiles(config: &ProjectConfig, warnings: &mut Vec<ConfigWarning>) {
+    let Some(profiles) = &config.profiles else {
+        return;
+    };
+    let mut names: Vec<&String> = profiles.keys().collect();
+    names.sort();
+
+    for name in names {
+        let profile = &profiles[name];
+        let field = format!("profiles.{}", name);
+        let sets_nothing = profile.output_folder.is_none()
+            && profile.jira.is_none()
+            && profile.squash.is_none()
+            && profile.llm.is_none()
+            && profile.templates.is_none()
+            && profile.checklist.is_none();
+
+        if sets_nothing && profile.extends.is_none() {
+            let active = config.active_profile.as_deref() == Some(name.as_str())
+                || config.default_profile.as_deref() == Some(name.as_str());
+            let extended = profiles
+                .values()
+                .any(|p| p.extends.as_deref() == Some(name.as_str()));
+            if !active && !extended {
+                warnings.push(warning(
+                    config,
+                    WarningCode::UnusedProfile,
+                    field,
+                    "overrides nothing and no profile extends it".to_string(),
+                    "remove the profile, or add the overrides it is meant for",
+                ));
+            }
+            continue;
+        }
+
+        // Errors are reported by validation; a broken profile is not linted here
+        let Ok(applied) = config.with_profile(name) else {
+            continue;
+        };
+        let identical = applied.output_folder == config.output_folder
+            && applied.jira == config.jira
+            && applied.squash == config.squash
+            && applied.llm == config.llm
+            && applied.templates == config.templates
+            && applied.checklist == config.checklist;
+        if identical {
+            warnings.push(warning(
+                config,
+                WarningCode::ProfileIdenticalToBase,
+                field,
+                "gives the same configuration as the base".to_string(),
+                "remove the overrides that repeat base values, or the profile itself",
+            ));
+        }
+    }
+}
+
+/// Template files that do not exist.
+fn lint_templates(config: &ProjectConfig, warnings: &mut Vec<ConfigWarning>) {
+    let Some(TemplatesConfig { cr, ppt, anomaly }) = &config.templates else {
+        return;
+    };
+    for (key, path) in [("cr", cr), ("ppt", ppt), ("anomaly", anomaly)] {
+        let Some(path) = path else {
+            continue;
+        };
+        if !config.resolve_path(path).is_file() {
+            warnings.push(warning(
+                config,
+                WarningCode::MissingTemplate,
+                format!("templates.{}", key),
+                format!("points to '{}' which does not exist", path),
+                "create the template file, or remove the entry",
+            ));
+        }
+    }
+}
+
+/// Whether a secret field is written as a reference in the file.
+///
+/// Loaded configurations are already interpolated, so `${VAR}` references are
+/// looked up in the original text when available.
+fn is_reference(config: &ProjectConfig, field: &str, value: &str) -> bool {
+    if is_secret_reference(value) {
+        return true;
+    }
+    config
+        .source
+        .as_ref()
+        .and_then(|source| source.raw_value(field))
+        .is_some_and(|raw| raw.contains("${"))
+}
+
+/// Host of an `http(s)://` URL, without credentials, port or brackets.
+fn endpoint_host(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host_port = authority.rsplit('@').next()?;
+    if host_port.starts_with('[') {
+        return host_port.get(1..host_port.find(']')?);
+    }
+    host_port.split(':').next()
+}
+
+/// Whether `host` is a bare name such as `jira` (not `localhost`, not an IP).
+fn is_single_label(host: &str) -> bool {
+    !host.is_empty()
+        && !host.contains('.')
+        && !host.contains(':')
+        && !host.eq_ignore_ascii_case("localhost")
+        && !host.contains("${")
+}
+
+/// Build a warning located in the configuration file when possible.
+fn warning(
+    config: &ProjectConfig,
+    code: WarningCode,
+    field: String,
+    message: String,
+    hint: &str,
+) -> ConfigWarning {
+    let location = config
+        .source
+        .as_ref()
+        .and_then(|source| source.locate_field(&field))
+        .map(Box::new);
+    ConfigWarning {
+        code,
+        field,
+        message,
+        hint: hint.to_string(),
+        location,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> ProjectConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn codes(warnings: &[ConfigWarning]) -> Vec<(&'static str, &str)> {
+        warnings
+            .iter()
+            .filter(|w| w.code != WarningCode::OutputFolder)
+            .map(|w| (w.code.code(), w.field.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_plaintext_secrets_and_endpoints() {
+        let config = parse(
+            r#"
+project_name: "demo"
+output_folder: "."
+jira:
+  endpoint: "http://jira.example.com"
+  token: "abc123"
+squash:
+  endpoint: "https://squash:8443"
+  password: "${SECRET:squash-password}"
+llm:
+  mode: "auto"
+  local_endpoint: "http://localhost:11434"
+  cloud_enabled: true
+  cloud_endpoint: "http://api.example.com/v1"
+  cloud_model: "gpt-4o-mini"
+  api_key: "${SECRET:llm-api-key}"
+"#,
+        );
+        let warnings = lint_config(&config);
+
+        assert_eq!(
+            codes(&warnings),
+            [
+                ("W001", "jira.token"),
+                ("W002", "jira.endpoint"),
+                ("W002", "llm.cloud_endpoint"),
+                ("W005", "squash.endpoint"),
+            ]
+        );
+        assert!(!warnings[0].to_string().contains("abc123"));
+    }
+
+    #[test]
+    fn test_identical_and_unused_profiles() {
+        let config = parse(
+            r#"
+project_name: "demo"
+output_folder: "./output"
+jira:
+  endpoint: "https://jira.example.com"
+profiles:
+  same:
+    output_folder: "./output"
+  empty:
+  base:
+  child:
+    extends: base
+    output_folder: "./child"
+"#,
+        );
+        let warnings = lint_config(&config);
+
+        assert_eq!(
+            codes(&warnings),
+            [("W003", "profiles.same"), ("W004", "profiles.empty")]
+        );
+    }
+
+    #[test]
+    fn test_missing_templates_resolve_against_base_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("templates")).unwrap();
+        std::fs::write(dir.path().join("templates").join("cr.md"), "# CR").unwrap();
+
+        let mut config = parse(
+            "project_name: \"demo\"\noutput_folder: \".\"\ntemplates:\n  cr: \"./templates/cr.md\"\n  ppt: \"./templates/report.pptx\"\n",
+        );
+        config.base_dir = Some(dir.path().to_path_buf());
+
+        let warnings = lint_config(&config);
+        assert_eq!(codes(&warnings), [("W006", "templates.ppt")]);
+    }
+
+    #[test]
+    fn test_endpoint_host() {
+        assert_eq!(endpoint_host("https://u:p@jira:8080/x"), Some("jira"));
+        assert_eq!(endpoint_host("http://[::1]:11434"), Some("::1"));
+        assert!(!is_single_label("::1"));
+        assert!(!is_single_label("localhost"));
+        assert!(is_single_label("squash"));
+    }
+}
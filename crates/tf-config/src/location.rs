@@ -0,0 +1,882 @@
+//! Source locations for configuration errors.
+//!
+//! When a configuration file is loaded, a [`ConfigSource`] indexes the position of
+//! every key so that field-scoped errors (e.g. `jira.endpoint`) can point at the
+//! exact line and column, with a rendered snippet:
+//!
+//! ```text
+//! Invalid configuration: field 'jira.endpoint' must be a valid URL with host. Expected: a URL like https://jira.example.com
+//!   --> config.yaml:12:5
+//!    |
+//! 12 |     endpoint: "not-a-url"
+//!    |     ^^^^^^^^
+//! ```
+//!
+//! # Limitations
+//!
+//! Keys are indexed with a line-based scanner that understands block mappings,
+//! block sequences, block scalars (`|`, `>`) and flow mappings written on one
+//! line (`jira: {endpoint: ...}`), which covers the documented config.yaml
+//! layout. See [`ConfigSource`] for what it does not index; errors on such keys
+//! fall back to the nearest indexed parent key.
+//!
+//! Pretty-printed JSON is indexed by the same scanner (one key per line). TOML
+//! files get their own scanner for `[table]`, `[[array]]` headers and
+//! `key = value` lines, dotted keys included; inline tables are not indexed.
+//!
+//! # Security
+//!
+//! Snippets never show the value of sensitive keys (`token`, `password`,
+//! `api_key`, ...) and URLs are passed through
+//! [`redact_url_sensitive_params`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::config::redact_url_sensitive_params;
+use crate::error::ConfigError;
+use crate::format::ConfigFormat;
+
+/// Keys whose values are replaced by `[REDACTED]` in snippets.
+const SENSITIVE_KEYS: &[&str] = &[
+    "token",
+    "password",
+    "api_key",
+    "apikey",
+    "secret",
+    "client_secret",
+    "private_key",
+];
+
+/// Position of an offending key in a configuration file.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Path of the configuration file
+    pub path: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number, in characters
+    pub column: usize,
+    /// Rendered source line with a caret under the offending key
+    pub snippet: String,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
+
+impl fmt::Debug for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceLocation")
+            .field("path", &self.path)
+            .field("line", &self.line)
+            .field("column", &self.column)
+            .finish()
+    }
+}
+
+/// Format an optional location as an error message suffix.
+pub(crate) fn format_location(location: &Option<Box<SourceLocation>>) -> String {
+    match location {
+        Some(loc) => format!("\n  --> {}\n{}", loc, loc.snippet),
+        None => String::new(),
+    }
+}
+
+/// Key position index of a loaded configuration file.
+///
+/// Attached to [`ProjectConfig::source`](crate::ProjectConfig::source) by
+/// [`load_config`](crate::load_config) so that errors raised later (e.g. when a
+/// profile is applied) can still be located. Fragments pulled in with
+/// `include:` are indexed too, after the file itself, so a key points at the
+/// file its effective value comes from. The `Debug` output only shows the file
+/// path, never the file content.
+///
+/// Columns count characters, not bytes, so a caret stays under its key on a
+/// line with non-ASCII text before it.
+///
+/// # Limitations
+///
+/// Positions come from a line scanner, not from the YAML parser, which does not
+/// report them for values that parse. Keys it does not index are located at
+/// their nearest indexed parent:
+///
+/// - flow mappings spanning several lines, and mappings inside flow sequences
+///   (`criteria: [{id: a}]`)
+/// - keys merged from an anchor (`<<: *defaults`), which are only indexed where
+///   the anchor is defined
+/// - complex keys (`? key`)
+///
+/// Continuation lines of multi-line plain or quoted scalars are scanned like any
+/// other line, so text looking like `key: value` there can be indexed as a key.
+#[derive(Clone)]
+pub struct ConfigSource {
+    /// The configuration file first, then its fragments by decreasing priority
+    files: Vec<SourceFile>,
+}
+
+/// One indexed file of a [`ConfigSource`].
+#[derive(Clone)]
+struct SourceFile {
+    path: PathBuf,
+    format: ConfigFormat,
+    lines: Vec<String>,
+    /// Dotted key path -> (1-based line, 1-based byte column, key length in bytes)
+    keys: HashMap<String, (usize, usize, usize)>,
+}
+
+impl fmt::Debug for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigSource")
+            .field("path", &self.path())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ConfigSource {
+    /// Index the keys of a document read from `path`, in the format given by
+    /// its extension (see [`ConfigFormat::from_path`]).
+    pub fn new(path: &Path, content: &str) -> Self {
+        let format = ConfigFormat::from_path(path);
+        let lines: Vec<String> = content.lines().map(str::to_string).collect();
+        let keys = match format {
+            ConfigFormat::Toml => index_toml_keys(&lines),
+            ConfigFormat::Yaml | ConfigFormat::Json => index_keys(&lines),
+        };
+        Self {
+            files: vec![SourceFile {
+                path: path.to_path_buf(),
+                format,
+                lines,
+                keys,
+            }],
+        }
+    }
+
+    /// Path of the indexed file.
+    pub fn path(&self) -> &Path {
+        &self.files[0].path
+    }
+
+    /// Paths of the indexed files: the configuration file, then its fragments.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|file| file.path.as_path())
+    }
+
+    /// Add the index of an included fragment, with a lower priority than the
+    /// files already indexed.
+    pub(crate) fn push_fragment(&mut self, fragment: ConfigSource) {
+        self.files.extend(fragment.files);
+    }
+
+    /// Locate a dotted field path (e.g. `profiles.dev.jira.endpoint`).
+    ///
+    /// If the field itself is absent from the file (e.g. a missing required
+    /// field), the nearest parent key that exists is returned instead.
+    pub fn locate_field(&self, field: &str) -> Option<SourceLocation> {
+        let mut path = field.strip_prefix("root.").unwrap_or(field);
+        loop {
+            if let Some(location) = self.locate_key(path) {
+                return Some(location);
+            }
+            path = &path[..path.rfind('.')?];
+        }
+    }
+
+    /// Whether the file (or one of its fragments) sets the key at this exact
+    /// dotted path.
+    pub fn contains_key(&self, field: &str) -> bool {
+        self.find_key(field).is_some()
+    }
+
+    /// Text written after the key at this exact dotted path, before any
+    /// interpolation (e.g. `"${JIRA_TOKEN}"`), if the value is on the key line.
+    pub(crate) fn raw_value(&self, field: &str) -> Option<&str> {
+        let (file, (line, column, width)) = self.find_key(field)?;
+        let text = &file.lines[line - 1];
+        let rest = text.get(column - 1 + width..)?;
+        let separator = match file.format {
+            ConfigFormat::Toml => '=',
+            ConfigFormat::Yaml | ConfigFormat::Json => ':',
+        };
+        let mut value = rest.trim_start().strip_prefix(separator)?.trim();
+        // In a flow mapping, the value ends at the next entry
+        let in_flow = text[..column - 1].trim_end().ends_with(['{', ',']);
+        if in_flow && file.format == ConfigFormat::Yaml {
+            let end = match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => value[1..].find(quote).map(|i| i + 2),
+                _ => value.find([',', '}']),
+            };
+            value = value[..end.unwrap_or(value.len())].trim_end();
+        }
+        (!value.is_empty()).then_some(value)
+    }
+
+    /// Locate a raw position in the configuration file, as reported by the
+    /// parser.
+    pub fn locate_position(&self, line: usize, column: usize) -> Option<SourceLocation> {
+        let file = &self.files[0];
+        if line == 0 || line > file.lines.len() {
+            return None;
+        }
+        Some(file.location(line, column.max(1), 1))
+    }
+
+    /// Attach a location to `err` (and to each error of a
+    /// [`ConfigError::Multiple`]) when it is field-scoped and not yet located.
+    ///
+    /// `candidates` are tried in order, each one as a prefix of the error field,
+    /// before the field itself; this lets errors on a merged profile point at the
+    /// profile override when it exists.
+    pub(crate) fn attach(&self, err: ConfigError, candidates: &[String]) -> ConfigError {
+        if let ConfigError::Multiple(errors) = err {
+            return ConfigError::Multiple(
+                errors
+                    .into_iter()
+                    .map(|e| self.attach(e, candidates))
+                    .collect(),
+            );
+        }
+        if err.location().is_some() {
+            return err;
+        }
+        let Some(field) = err.field().map(str::to_string) else {
+            return err;
+        };
+
+        let mut paths: Vec<String> = candidates
+            .iter()
+            .map(|prefix| format!("{}{}", prefix, field))
+            .collect();
+        paths.push(field);
+
+        let exact = paths.iter().find_map(|p| self.locate_key(p));
+        match exact.or_else(|| paths.iter().find_map(|p| self.locate_field(p))) {
+            Some(location) => err.with_location(location),
+            None => err,
+        }
+    }
+
+    /// File and position of the key at this exact dotted path, in priority order.
+    fn find_key(&self, field: &str) -> Option<(&SourceFile, (usize, usize, usize))> {
+        self.files
+            .iter()
+            .find_map(|file| file.keys.get(field).map(|&position| (file, position)))
+    }
+
+    fn locate_key(&self, field: &str) -> Option<SourceLocation> {
+        self.find_key(field).map(|(file, (line, column, width))| {
+            let (column, width) = char_span(&file.lines[line - 1], column, width);
+            file.location(line, column, width)
+        })
+    }
+}
+
+impl SourceFile {
+    /// Location of a character column and width on `line`.
+    fn location(&self, line: usize, column: usize, width: usize) -> SourceLocation {
+        SourceLocation {
+            path: self.path.clone(),
+            line,
+            column,
+            snippet: render_snippet(self.format, &self.lines[line - 1], line, column, width),
+        }
+    }
+}
+
+/// Character column and width of a key indexed by byte column and byte length.
+fn char_span(line: &str, column: usize, width: usize) -> (usize, usize) {
+    let start = (column - 1).min(line.len());
+    let end = (start + width).min(line.len());
+    match (line.get(..start), line.get(start..end)) {
+        (Some(before), Some(key)) => (before.chars().count() + 1, key.chars().count()),
+        _ => (column, width),
+    }
+}
+
+/// Render one source line with a caret marker, redacting sensitive values.
+fn render_snippet(
+    format: ConfigFormat,
+    source_line: &str,
+    line: usize,
+    column: usize,
+    width: usize,
+) -> String {
+    let gutter = line.to_string().len();
+    let pad = " ".repeat(gutter);
+    format!(
+        "{pad} |\n{line} | {}\n{pad} | {}{}",
+        redact_line(format, source_line),
+        " ".repeat(column.saturating_sub(1)),
+        "^".repeat(width.max(1)),
+    )
+}
+
+/// Hide the value of sensitive keys and credentials embedded in URLs.
+fn redact_line(format: ConfigFormat, source_line: &str) -> String {
+    let split = match format {
+        ConfigFormat::Toml => split_toml_key(source_line),
+        ConfigFormat::Yaml | ConfigFormat::Json => split_key(source_line),
+    };
+    let Some((_, key, value_start)) = split else {
+        return source_line.to_string();
+    };
+    // Dotted TOML keys (`jira.token = ...`) are judged on their last segment
+    let key_lower = key.rsplit('.').next().unwrap_or(&key).to_lowercase();
+    let value = source_line[value_start..].trim();
+    if value.is_empty() {
+        return source_line.to_string();
+    }
+    let head = &source_line[..value_start];
+    if SENSITIVE_KEYS.contains(&key_lower.as_str()) {
+        return format!("{} [REDACTED]", head.trim_end());
+    }
+    if value.contains("://") {
+        return format!("{} {}", head.trim_end(), redact_url_sensitive_params(value));
+    }
+    source_line.to_string()
+}
+
+/// Split a line into (key column offset, key, offset of the value).
+///
+/// Handles an optional leading `- ` sequence marker and quoted keys.
+fn split_key(line: &str) -> Option<(usize, String, usize)> {
+    let trimmed = line.trim_start();
+    let mut offset = line.len() - trimmed.len();
+    let mut rest = trimmed;
+    while let Some(after) = rest.strip_prefix("- ") {
+        let after_trimmed = after.trim_start();
+        offset += rest.len() - after_trimmed.len();
+        rest = after_trimmed;
+    }
+    if rest.starts_with('#') || rest.is_empty() {
+        return None;
+    }
+
+    let (key, key_len) = match rest.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let end = rest[1..].find(quote)? + 1;
+            (rest[1..end].to_string(), end + 1)
+        }
+        _ => {
+            let end = find_key_separator(rest)?;
+            (rest[..end].trim_end().to_string(), end)
+        }
+    };
+
+    let after_key = &rest[key_len..];
+    let after_key_trimmed = after_key.trim_start();
+    let colon = after_key_trimmed.strip_prefix(':')?;
+    if !(colon.is_empty() || colon.starts_with(' ') || colon.starts_with('\t')) {
+        return None;
+    }
+    if key.is_empty() || key.starts_with(['{', '[', '&', '*', '!', '|', '>']) {
+        return None;
+    }
+    let value_start = offset + key_len + (after_key.len() - after_key_trimmed.len()) + 1;
+    Some((offset, key, value_start))
+}
+
+/// Position of the `:` ending a plain (unquoted) key.
+fn find_key_separator(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    (0..bytes.len()).find(|&i| {
+        bytes[i] == b':' && (i + 1 == bytes.len() || bytes[i + 1] == b' ' || bytes[i + 1] == b'\t')
+    })
+}
+
+/// Build the dotted-path index of every key in a block-style YAML document.
+///
+/// Sequence items are indexed by position (e.g. `include.0`,
+/// `checklist.criteria.1.id`).
+fn index_keys(lines: &[String]) -> HashMap<String, (usize, usize, usize)> {
+    struct Frame {
+        indent: usize,
+        segment: String,
+        is_item: bool,
+    }
+
+    let mut keys = HashMap::new();
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut item_counters: HashMap<String, usize> = HashMap::new();
+    // Lines more indented than this belong to a block scalar (| or >)
+    let mut block_scalar_indent: Option<usize> = None;
+
+    let path_of = |stack: &[Frame]| -> String {
+        stack
+            .iter()
+            .map(|f| f.segment.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    };
+
+    for (idx, raw) in lines.iter().enumerate() {
+        let line_no = idx + 1;
+        let trimmed = raw.trim_start();
+        let indent = raw.len() - trimmed.len();
+
+        if let Some(scalar_indent) = block_scalar_indent {
+            if trimmed.is_empty() || indent > scalar_indent {
+                continue;
+            }
+            block_scalar_indent = None;
+        }
+        if trimmed.is_empty()
+            || trimmed.starts_with('#')
+            || trimmed.starts_with("---")
+            || trimmed.starts_with("...")
+        {
+            continue;
+        }
+
+        let mut col = indent;
+        let mut rest = trimmed;
+
+        // Sequence items: "- value" or "- key: value"
+        while rest == "-" || rest.starts_with("- ") {
+            while stack
+                .last()
+                .is_some_and(|f| f.indent > col || (f.indent == col && f.is_item))
+            {
+                stack.pop();
+            }
+            let parent = path_of(&stack);
+            let counter = item_counters.entry(parent.clone()).or_insert(0);
+            let index = *counter;
+            *counter += 1;
+            stack.push(Frame {
+                indent: col,
+                segment: index.to_string(),
+                is_item: true,
+            });
+            let item_path = path_of(&stack);
+            keys.entry(item_path).or_insert((line_no, col + 1, 1));
+
+            let after = rest[1..].trim_start();
+            col += rest.len() - after.len();
+            rest = after;
+        }
+
+        // "- {id: a, weight: 2}": the keys belong to the sequence item
+        if rest.starts_with('{') && stack.last().is_some_and(|f| f.is_item) {
+            index_flow_mapping(raw, col, &path_of(&stack), line_no, &mut keys);
+            continue;
+        }
+
+        let Some((_, key, value_start)) = split_key(rest) else {
+            continue;
+        };
+
+        // Keys inside a sequence item are more indented than its "-" marker, so
+        // any frame at or beyond this column is a sibling or a closed block
+        while stack.last().is_some_and(|f| f.indent >= col) {
+            stack.pop();
+        }
+
+        let width = if rest.starts_with(['"', '\'']) {
+            key.len() + 2
+        } else {
+            key.len()
+        };
+        stack.push(Frame {
+            indent: col,
+            segment: key,
+            is_item: false,
+        });
+        keys.entry(path_of(&stack))
+            .or_insert((line_no, col + 1, width));
+
+        let value = rest[value_start..].trim_start();
+        if value.starts_with('|') || value.starts_with('>') {
+            block_scalar_indent = Some(col);
+        }
+        let value = skip_anchor(value);
+        if value.starts_with('{') {
+            index_flow_mapping(
+                raw,
+                raw.len() - value.len(),
+                &path_of(&stack),
+                line_no,
+                &mut keys,
+            );
+        }
+    }
+
+    keys
+}
+
+/// Value after an optional anchor (`&defaults {...}`).
+fn skip_anchor(value: &str) -> &str {
+    match value.strip_prefix('&') {
+        Some(anchored) => anchored
+            .find(char::is_whitespace)
+            .map_or("", |end| anchored[end..].trim_start()),
+        None => value,
+    }
+}
+
+/// Index the keys of a flow mapping opening at byte `start` of `line` under
+/// `prefix`, nested flow mappings included.
+///
+/// Only the part on this line is indexed; flow sequences are skipped.
+fn index_flow_mapping(
+    line: &str,
+    start: usize,
+    prefix: &str,
+    line_no: usize,
+    keys: &mut HashMap<String, (usize, usize, usize)>,
+) {
+    let child = |parent: &str, key: &str| {
+        if parent.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", parent, key)
+        }
+    };
+    // Paths of the open mappings, innermost last
+    let mut open: Vec<String> = Vec::new();
+    // Path of the last key, while its value may still open a nested mapping
+    let mut pending: Option<String> = None;
+    let mut expect_key = false;
+    let mut i = start;
+
+    while let Some(c) = line[i..].chars().next() {
+        match c {
+            '{' => {
+                let path = match (open.is_empty(), pending.take()) {
+                    (true, _) => prefix.to_string(),
+                    (false, Some(path)) => path,
+                    (false, None) => return,
+                };
+                open.push(path);
+                expect_key = true;
+            }
+            '}' => {
+                open.pop();
+                if open.is_empty() {
+                    return;
+                }
+                expect_key = false;
+            }
+            ']' => return,
+            ',' => {
+                pending = None;
+                expect_key = true;
+            }
+            '[' => {
+                let mut depth = 0;
+                let Some(len) = line[i..].find(|c| {
+                    depth += match c {
+                        '[' => 1,
+                        ']' => -1,
+                        _ => 0,
+                    };
+                    depth == 0
+                }) else {
+                    return;
+                };
+                pending = None;
+                i += len;
+            }
+            '"' | '\'' => {
+                let Some(len) = line[i + 1..].find(c) else {
+                    return;
+                };
+                if expect_key {
+                    let path = child(
+                        open.last().map_or("", String::as_str),
+                        &line[i + 1..i + 1 + len],
+                    );
+                    keys.entry(path.clone())
+                        .or_insert((line_no, i + 1, len + 2));
+                    pending = Some(path);
+                    expect_key = false;
+                } else {
+                    pending = None;
+                }
+                i += len + 1;
+            }
+            c if c.is_whitespace() || c == ':' => {}
+            _ => {
+                let len = line[i..]
+                    .find([':', ',', '{', '}', '[', ']'])
+                    .unwrap_or(line.len() - i);
+                let text = line[i..i + len].trim_end();
+                if expect_key && line[i + len..].starts_with(':') {
+                    let path = child(open.last().map_or("", String::as_str), text);
+                    keys.entry(path.clone())
+                        .or_insert((line_no, i + 1, text.len()));
+                    pending = Some(path);
+                } else {
+                    pending = None;
+                }
+                expect_key = false;
+                i += len;
+                continue;
+            }
+        }
+        i += c.len_utf8();
+    }
+}
+
+/// Split a TOML `key = value` line into (key column offset, key, offset of
+/// the value). The key is returned as written, dotted and quoted parts included.
+fn split_toml_key(line: &str) -> Option<(usize, String, usize)> {
+    let trimmed = line.trim_start();
+    let offset = line.len() - trimmed.len();
+    if trimmed.starts_with(['#', '[', '{']) {
+        return None;
+    }
+
+    let mut quote = None;
+    let separator = trimmed.char_indices().find_map(|(i, c)| match (quote, c) {
+        (None, '"' | '\'') => {
+            quote = Some(c);
+            None
+        }
+        (Some(q), _) if c == q => {
+            quote = None;
+            None
+        }
+        (None, '=') => Some(i),
+        _ => None,
+    })?;
+
+    let key = trimmed[..separator].trim_end();
+    let is_key_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ' ');
+    let mut segments_ok = !key.is_empty();
+    let mut in_quotes = None;
+    for c in key.chars() {
+        match in_quotes {
+            Some(q) if c == q => in_quotes = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => in_quotes = Some(c),
+            None => segments_ok &= is_key_char(c),
+        }
+    }
+    segments_ok.then(|| (offset, key.to_string(), offset + separator + 1))
+}
+
+/// Segments of a TOML dotted key (`profiles."my dev".jira`), unquoted.
+fn toml_key_segments(key: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    for c in key.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '.' => segments.push(std::mem::take(&mut current).trim().to_string()),
+            None => current.push(c),
+        }
+    }
+    segments.push(current.trim().to_string());
+    segments
+}
+
+/// Build the dotted-path index of every key in a TOML document.
+///
+/// `[table]` headers set the prefix of the following keys; `[[array]]` items
+/// are indexed by position, like YAML sequences (`checklist.criteria.1.id`).
+fn index_toml_keys(lines: &[String]) -> HashMap<String, (usize, usize, usize)> {
+    let mut keys = HashMap::new();
+    let mut table: Vec<String> = Vec::new();
+    let mut item_counters: HashMap<String, usize> = HashMap::new();
+    // Closing delimiter of the multi-line string being skipped
+    let mut multiline_string: Option<&str> = None;
+
+    for (idx, raw) in lines.iter().enumerate() {
+        let line_no = idx + 1;
+        if let Some(delimiter) = multiline_string {
+            if raw.contains(delimiter) {
+                multiline_string = None;
+            }
+            continue;
+        }
+        let trimmed = raw.trim_start();
+        let indent = raw.len() - trimmed.len();
+
+        if let Some(header) = trimmed.strip_prefix('[') {
+            let is_array = header.starts_with('[');
+            let header = header.trim_start_matches('[');
+            let Some(end) = header.find(']') else {
+                continue;
+            };
+            let column = indent + if is_array { 3 } else { 2 };
+            table = toml_key_segments(&header[..end]);
+            let path = table.join(".");
+            keys.entry(path.clone()).or_insert((line_no, column, end));
+            if is_array {
+                let counter = item_counters.entry(path.clone()).or_insert(0);
+                table.push(counter.to_string());
+                *counter += 1;
+                keys.insert(table.join("."), (line_no, column, end));
+            }
+            continue;
+        }
+
+        let Some((offset, key, value_start)) = split_toml_key(raw) else {
+            continue;
+        };
+        let mut path = table.clone();
+        for segment in toml_key_segments(&key) {
+            path.push(segment);
+            keys.entry(path.join("."))
+                .or_insert((line_no, offset + 1, key.len()));
+        }
+
+        let value = raw[value_start..].trim_start();
+        for delimiter in ["\"\"\"", "'''"] {
+            if let Some(after) = value.strip_prefix(delimiter) {
+                if !after.contains(delimiter) {
+                    multiline_string = Some(delimiter);
+                }
+            }
+        }
+    }
+
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(yaml: &str) -> ConfigSource {
+        ConfigSource::new(Path::new("config.yaml"), yaml)
+    }
+
+    #[test]
+    fn test_indexes_nested_keys_with_columns() {
+        let src = source(
+            "project_name: demo\njira:\n  endpoint: \"x\"\nprofiles:\n  dev:\n    jira:\n      token: t\n",
+        );
+        let loc = src.locate_field("jira.endpoint").unwrap();
+        assert_eq!((loc.line, loc.column), (3, 3));
+        let loc = src.locate_field("profiles.dev.jira.token").unwrap();
+        assert_eq!((loc.line, loc.column), (7, 7));
+        assert_eq!(loc.to_string(), "config.yaml:7:7");
+    }
+
+    #[test]
+    fn test_missing_field_falls_back_to_parent() {
+        let src = source("project_name: demo\nllm:\n  mode: cloud\n");
+        let loc = src.locate_field("llm.api_key").unwrap();
+        assert_eq!(loc.line, 2);
+        assert!(src.locate_field("squash.endpoint").is_none());
+    }
+
+    #[test]
+    fn test_sequences_and_block_scalars() {
+        let src = source(
+            "include:\n  - a.yaml\n  - b.yaml\nnotes: |\n  fake: key\nitems:\n- id: one\n  weight: 2\n- id: two\nafter: x\n",
+        );
+        assert_eq!(src.locate_field("include.1").unwrap().line, 3);
+        assert!(!src.contains_key("notes.fake"));
+        assert_eq!(src.locate_field("items.0.weight").unwrap().line, 8);
+        assert_eq!(src.locate_field("items.1.id").unwrap().line, 9);
+        assert_eq!(src.locate_field("after").unwrap().line, 10);
+    }
+
+    #[test]
+    fn test_snippet_has_caret_under_key() {
+        let src = source("jira:\n  endpoint: \"nope\"\n");
+        let loc = src.locate_field("jira.endpoint").unwrap();
+        assert_eq!(loc.snippet, "  |\n2 |   endpoint: \"nope\"\n  |   ^^^^^^^^");
+    }
+
+    #[test]
+    fn test_flow_mappings_on_one_line() {
+        let src = source(
+            "jira: {endpoint: \"not-a-url\", \"token\": \"${JIRA_TOKEN}\"}\nprofiles:\n  dev: &dev {llm: {mode: cloud}, criteria: [{id: a}]}\nitems:\n  - {id: one, weight: 2}\n",
+        );
+        let loc = src.locate_field("jira.endpoint").unwrap();
+        assert_eq!((loc.line, loc.column), (1, 8));
+        assert!(
+            loc.snippet.ends_with("|        ^^^^^^^^"),
+            "{}",
+            loc.snippet
+        );
+        assert_eq!(src.raw_value("jira.endpoint"), Some("\"not-a-url\""));
+        assert_eq!(src.raw_value("jira.token"), Some("\"${JIRA_TOKEN}\""));
+        let loc = src.locate_field("profiles.dev.llm.mode").unwrap();
+        assert_eq!((loc.line, loc.column), (3, 20));
+        assert_eq!(src.locate_field("items.0.weight").unwrap().column, 15);
+        // Mappings inside flow sequences fall back to the sequence key
+        assert!(!src.contains_key("profiles.dev.criteria.0.id"));
+        assert_eq!(
+            src.locate_field("profiles.dev.criteria.0.id")
+                .unwrap()
+                .column,
+            34
+        );
+    }
+
+    #[test]
+    fn test_columns_count_characters() {
+        let src = source("jira:\n  project_key: \"Équipe\" # équipe: \"test\"\nlabels: {générique: x, endpoint: y}\n");
+        let loc = src.locate_field("labels.endpoint").unwrap();
+        assert_eq!((loc.line, loc.column), (3, 24));
+        assert_eq!(
+            loc.snippet,
+            "  |\n3 | labels: {générique: x, endpoint: y}\n  |                        ^^^^^^^^"
+        );
+        let loc = src.locate_field("labels.générique").unwrap();
+        assert_eq!(loc.column, 10);
+        assert!(
+            loc.snippet.ends_with("|          ^^^^^^^^^"),
+            "{}",
+            loc.snippet
+        );
+    }
+
+    #[test]
+    fn test_snippet_redacts_sensitive_values() {
+        let src = source(
+            "jira:\n  endpoint: \"https://user:pw@jira.example.com\"\n  token: \"super-secret\"\n",
+        );
+        let token = src.locate_field("jira.token").unwrap();
+        assert!(!token.snippet.contains("super-secret"));
+        assert!(token.snippet.contains("token: [REDACTED]"));
+        let endpoint = src.locate_field("jira.endpoint").unwrap();
+        assert!(!endpoint.snippet.contains("user:pw"));
+    }
+
+    #[test]
+    fn test_toml_tables_dotted_keys_and_arrays() {
+        let src = ConfigSource::new(
+            Path::new("config.toml"),
+            "project_name = \"demo\"\nnotes = \"\"\"\nfake = 1\n\"\"\"\n\n[jira]\nendpoint = \"x\"\ntoken = \"super-secret\"\n\n[profiles.dev]\nllm.mode = \"local\"\n\n[[checklist.criteria]]\nid = \"a\"\n[[checklist.criteria]]\nid = \"b\"\n",
+        );
+        let loc = src.locate_field("jira.endpoint").unwrap();
+        assert_eq!((loc.line, loc.column), (7, 1));
+        assert!(!src.contains_key("fake"));
+        assert_eq!(src.locate_field("profiles.dev.llm.mode").unwrap().line, 11);
+        assert_eq!(
+            src.locate_field("checklist.criteria.1.id").unwrap().line,
+            16
+        );
+        assert_eq!(src.raw_value("jira.endpoint"), Some("\"x\""));
+
+        let token = src.locate_field("jira.token").unwrap();
+        assert!(token.snippet.contains("token = [REDACTED]"));
+        assert!(!token.snippet.contains("super-secret"));
+    }
+
+    #[test]
+    fn test_attach_prefers_profile_override() {
+        let src = source(
+            "jira:\n  endpoint: https://jira.example.com\nprofiles:\n  dev:\n    jira:\n      endpoint: bad\n",
+        );
+        let err = src.attach(
+            ConfigError::invalid_value("jira.endpoint", "is bad", "a URL"),
+            &["profiles.dev.".to_string()],
+        );
+        assert_eq!(err.location().unwrap().line, 6);
+        assert!(err.to_string().contains("--> config.yaml:6:7"));
+    }
+}
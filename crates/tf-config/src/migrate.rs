@@ -0,0 +1,480 @@
+//! Versioned configuration schema and migrations.
+//!
+//! `config.yaml` carries a top-level `config_version`. Files written before the
+//! key existed are version 0. When the schema changes (a key is renamed or
+//! moved), [`CURRENT_CONFIG_VERSION`] is bumped and a migration step is added
+//! that upgrades a document from the previous version.
+//!
+//! - [`load_config`](crate::load_config) migrates older documents in memory, so
+//!   existing files keep loading; a document newer than this version of tf is
+//!   rejected with a hint to upgrade
+//! - [`plan_migration`] reports what would change, without touching the file
+//!   (YAML, TOML or JSON, picked from the extension like `load_config`)
+//! - [`migrate_config_file`] rewrites the file in place; only YAML files can be
+//!   rewritten, TOML and JSON files that need an upgrade are rejected with the
+//!   line to add by hand
+//!
+//! Steps run one version at a time (0 -> 1 -> 2 ...), each one listing the
+//! changes it made in the [`MigrationReport`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::migrate_config_file;
+//!
+//! let report = migrate_config_file(Path::new("config.yaml"))?;
+//! println!("{}", report);
+//! # Ok::<(), tf_config::ConfigError>(())
+//! ```
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde_yaml::{Mapping, Value};
+
+use crate::config::ProjectConfig;
+use crate::error::ConfigError;
+use crate::format::ConfigFormat;
+
+/// Schema version written by this version of tf.
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
+/// Top-level key holding the schema version.
+pub const CONFIG_VERSION_KEY: &str = "config_version";
+
+/// One upgrade step, from version `from` to `from + 1`.
+struct Migration {
+    /// Version the step upgrades from
+    from: u32,
+    /// Restructure the document, describing each change made
+    apply: fn(&mut Mapping, &mut Vec<String>),
+}
+
+/// Migration steps, in version order.
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    apply: migrate_v0_to_v1,
+}];
+
+/// Version 1 introduces `config_version`; the rest of the schema is unchanged.
+fn migrate_v0_to_v1(_document: &mut Mapping, _changes: &mut Vec<String>) {}
+
+/// A change made by a migration step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationChange {
+    /// Version the document was upgraded to by the step
+    pub to_version: u32,
+    /// What changed (e.g. "Set config_version to 1")
+    pub description: String,
+}
+
+/// Outcome of migrating a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// File the report is about, when migrating a file
+    pub path: Option<PathBuf>,
+    /// Version of the document before migration (0 when `config_version` is absent)
+    pub from_version: u32,
+    /// Version after migration ([`CURRENT_CONFIG_VERSION`])
+    pub to_version: u32,
+    /// Changes, in the order they were made
+    pub changes: Vec<MigrationChange>,
+    /// Whether comments and formatting survive the migration.
+    ///
+    /// Steps that only set `config_version` edit the text in place. Steps that
+    /// restructure the document re-serialize it, which drops comments; the
+    /// original file is then kept next to it with a `.bak` extension.
+    pub comments_preserved: bool,
+}
+
+impl MigrationReport {
+    /// Whether the document was already at the current version.
+    pub fn is_up_to_date(&self) -> bool {
+        self.from_version == self.to_version
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self
+            .path
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "Configuration".to_string());
+        if self.is_up_to_date() {
+            return write!(f, "{} is up to date (version {})", name, self.to_version);
+        }
+        write!(
+            f,
+            "{} migrated from version {} to {}:",
+            name, self.from_version, self.to_version
+        )?;
+        for change in &self.changes {
+            write!(f, "\n  - [v{}] {}", change.to_version, change.description)?;
+        }
+        if !self.comments_preserved {
+            write!(
+                f,
+                "\nComments were not preserved; the original file is kept as .bak"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Upgrade a YAML document to the current version.
+///
+/// Returns the migrated text and the report. The text is unchanged when the
+/// document is up to date.
+///
+/// # Errors
+///
+/// - [`ConfigError::ParseError`] if the document is not valid YAML or does not
+///   match the configuration schema after migration
+/// - [`ConfigError::InvalidValue`] on `config_version` if it is not a number or
+///   is newer than [`CURRENT_CONFIG_VERSION`]
+pub fn migrate_yaml(content: &str) -> Result<(String, MigrationReport), ConfigError> {
+    let (text, report) = migrate_with(content, MIGRATIONS)?;
+    // Never hand back a document that would not load
+    serde_yaml::from_str::<ProjectConfig>(&text)?;
+    Ok((text, report))
+}
+
+/// Report what migrating the file at `path` would change, without writing it.
+///
+/// The file is parsed in the format of its extension (see
+/// [`ConfigFormat::from_path`]).
+///
+/// # Errors
+///
+/// - [`ConfigError::FileNotFound`] if `path` does not exist
+/// - [`ConfigError::SyntaxError`] if a TOML or JSON file cannot be parsed
+/// - Any error from [`migrate_yaml`]
+pub fn plan_migration(path: &Path) -> Result<MigrationReport, ConfigError> {
+    let content = read_config(path)?;
+    let format = ConfigFormat::from_path(path);
+    let (_, mut report) = migrate_yaml(&as_yaml(format, &content)?)?;
+    report.path = Some(path.to_path_buf());
+    Ok(report)
+}
+
+/// Upgrade the file at `path` in place.
+///
+/// Nothing is written when the file is up to date. When a step restructures the
+/// document, the original is first copied to `<path>.bak`.
+///
+/// # Errors
+///
+/// - Any error from [`plan_migration`]
+/// - [`ConfigError::InvalidValue`] on `config_version` if a TOML or JSON file
+///   needs an upgrade: those files are not rewritten, the hint gives the line
+///   to add by hand
+/// - [`ConfigError::IoError`] if the file or its backup cannot be written
+pub fn migrate_config_file(path: &Path) -> Result<MigrationReport, ConfigError> {
+    let content = read_config(path)?;
+    let format = ConfigFormat::from_path(path);
+    let (migrated, mut report) = migrate_yaml(&as_yaml(format, &content)?)?;
+    report.path = Some(path.to_path_buf());
+    if report.is_up_to_date() {
+        return Ok(report);
+    }
+    if format != ConfigFormat::Yaml {
+        let line = match format {
+            ConfigFormat::Json => format!("\"{}\": {}", CONFIG_VERSION_KEY, report.to_version),
+            _ => format!("{} = {}", CONFIG_VERSION_KEY, report.to_version),
+        };
+        return Err(ConfigError::invalid_value(
+            CONFIG_VERSION_KEY,
+            format!(
+                "is {}, and in-place migration only rewrites YAML files (this file is {})",
+                report.from_version, format
+            ),
+            format!("add `{}` at the top level of {}", line, path.display()),
+        ));
+    }
+
+    if !report.comments_preserved {
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(".bak");
+        std::fs::copy(path, PathBuf::from(backup))?;
+    }
+    // Write next to the file then rename, so an interrupted write never
+    // leaves a truncated config.yaml behind
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    std::fs::write(&temp, migrated)?;
+    std::fs::rename(&temp, path)?;
+    Ok(report)
+}
+
+/// Migrate a document that `load_config` is about to parse.
+///
+/// `document` is the file parsed in its own format (YAML, TOML or JSON), with
+/// its fragments merged. Returns whether steps restructured it; when they did
+/// not, the text can be parsed as is (up to date, or only `config_version`
+/// differs). Documents that are not mappings are left to the regular parser,
+/// which reports a better error.
+pub(crate) fn migrate_for_load(document: &mut Value) -> Result<bool, ConfigError> {
+    let Value::Mapping(document) = document else {
+        return Ok(false);
+    };
+    let from = document_version(document)?;
+    Ok(!run_steps(document, from, MIGRATIONS).is_empty())
+}
+
+/// Read a configuration file, reporting a missing file like `load_config`.
+fn read_config(path: &Path) -> Result<String, ConfigError> {
+    if !path.exists() {
+        return Err(ConfigError::FileNotFound {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(std::fs::read_to_string(path)?)
+}
+
+/// YAML text of a configuration file written in `format`, for the migration
+/// pipeline.
+fn as_yaml(format: ConfigFormat, content: &str) -> Result<String, ConfigError> {
+    if format == ConfigFormat::Yaml {
+        return Ok(content.to_string());
+    }
+    let document: Value = format
+        .deserialize(content)
+        .map_err(|e| e.into_config_error(format, None))?;
+    Ok(serde_yaml::to_string(&document)?)
+}
+
+/// Pipeline implementation, with the steps passed in for testability.
+fn migrate_with(
+    content: &str,
+    migrations: &[Migration],
+) -> Result<(String, MigrationReport), ConfigError> {
+    let mut document = match serde_yaml::from_str::<Value>(content)? {
+        Value::Mapping(document) => document,
+        // Let the schema check report what is wrong with the document
+        _ => Mapping::new(),
+    };
+    let from = document_version(&document)?;
+    let to = CURRENT_CONFIG_VERSION;
+
+    let mut report = MigrationReport {
+        path: None,
+        from_version: from,
+        to_version: to,
+        changes: Vec::new(),
+        comments_preserved: true,
+    };
+    if from == to {
+        return Ok((content.to_string(), report));
+    }
+
+    report.changes = run_steps(&mut document, from, migrations);
+    let restructured = !report.changes.is_empty();
+    report.changes.push(MigrationChange {
+        to_version: to,
+        description: format!("Set {} to {}", CONFIG_VERSION_KEY, to),
+    });
+
+    let text = if !restructured {
+        set_version_line(content, to)
+    } else {
+        report.comments_preserved = false;
+        document.insert(Value::from(CONFIG_VERSION_KEY), Value::from(to));
+        serde_yaml::to_string(&Value::Mapping(document))?
+    };
+    Ok((text, report))
+}
+
+/// Run the steps from version `from` on `document`, returning the changes they
+/// made to its structure.
+fn run_steps(document: &mut Mapping, from: u32, migrations: &[Migration]) -> Vec<MigrationChange> {
+    let mut changes = Vec::new();
+    for migration in migrations
+        .iter()
+        .filter(|m| m.from >= from && m.from < CURRENT_CONFIG_VERSION)
+    {
+        let mut descriptions = Vec::new();
+        (migration.apply)(document, &mut descriptions);
+        changes.extend(descriptions.into_iter().map(|description| MigrationChange {
+            to_version: migration.from + 1,
+            description,
+        }));
+    }
+    changes
+}
+
+/// `config_version` of a document (0 when absent).
+fn document_version(document: &Mapping) -> Result<u32, ConfigError> {
+    let Some(value) = document.get(CONFIG_VERSION_KEY) else {
+        return Ok(0);
+    };
+    let version = value
+        .as_u64()
+        .and_then(|v| u32::try_from(v).ok())
+        .ok_or_else(|| {
+            ConfigError::invalid_value(
+                CONFIG_VERSION_KEY,
+                "must be a non-negative integer",
+                format!("config_version: {}", CURRENT_CONFIG_VERSION),
+            )
+        })?;
+    if version > CURRENT_CONFIG_VERSION {
+        return Err(ConfigError::invalid_value(
+            CONFIG_VERSION_KEY,
+            format!(
+                "is {}, newer than the latest version supported by this tf ({})",
+                version, CURRENT_CONFIG_VERSION
+            ),
+            "upgrade tf to a version that supports this configuration",
+        ));
+    }
+    Ok(version)
+}
+
+/// Set the top-level `config_version` line, keeping the rest of the text
+/// (comments included) intact.
+fn set_version_line(content: &str, version: u32) -> String {
+    let line = format!("{}: {}", CONFIG_VERSION_KEY, version);
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    let existing = lines.iter().position(|l| {
+        l.strip_prefix(CONFIG_VERSION_KEY)
+            .is_some_and(|rest| rest.trim_start().starts_with(':'))
+    });
+    match existing {
+        Some(index) => lines[index] = line,
+        None => {
+            // Before the first key, after any header comment
+            let index = lines
+                .iter()
+                .position(|l| {
+                    let t = l.trim();
+                    !t.is_empty() && !t.starts_with('#') && t != "---"
+                })
+                .unwrap_or(lines.len());
+            lines.insert(index, line);
+        }
+    }
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY: &str = "# Project settings\nproject_name: \"demo\"\noutput_folder: \"./output\"\nllm:\n  mode: local\n  local_endpoint: \"http://localhost:11434\"\n";
+
+    /// A step moving `llm.local_endpoint` to `llm.endpoint`, for pipeline tests.
+    fn rename_endpoint(document: &mut Mapping, changes: &mut Vec<String>) {
+        if let Some(Value::Mapping(llm)) = document.get_mut("llm") {
+            if let Some(value) = llm.remove("local_endpoint") {
+                llm.insert(Value::from("endpoint"), value);
+                changes.push("Renamed llm.local_endpoint to llm.endpoint".to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_unversioned_document_gets_version_and_keeps_comments() {
+        let (text, report) = migrate_yaml(LEGACY).unwrap();
+
+        assert_eq!(report.from_version, 0);
+        assert_eq!(report.to_version, CURRENT_CONFIG_VERSION);
+        assert!(report.comments_preserved);
+        assert_eq!(report.changes.len(), 1);
+        assert!(text.starts_with("# Project settings\nconfig_version: 1\nproject_name:"));
+
+        let (again, report) = migrate_yaml(&text).unwrap();
+        assert!(report.is_up_to_date());
+        assert_eq!(again, text);
+        assert!(report.to_string().contains("up to date"));
+    }
+
+    #[test]
+    fn test_restructuring_steps_are_reported_in_order() {
+        let migrations = [Migration {
+            from: 0,
+            apply: rename_endpoint,
+        }];
+        let (text, report) = migrate_with(LEGACY, &migrations).unwrap();
+
+        assert!(!report.comments_preserved);
+        let descriptions: Vec<&str> = report
+            .changes
+            .iter()
+            .map(|c| c.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            [
+                "Renamed llm.local_endpoint to llm.endpoint",
+                "Set config_version to 1"
+            ]
+        );
+        let document: Value = serde_yaml::from_str(&text).unwrap();
+        assert_eq!(
+            document["llm"]["endpoint"],
+            Value::from("http://localhost:11434")
+        );
+        assert_eq!(document[CONFIG_VERSION_KEY], Value::from(1));
+    }
+
+    #[test]
+    fn test_newer_or_invalid_version_is_rejected() {
+        let err =
+            migrate_yaml("config_version: 99\nproject_name: x\noutput_folder: y\n").unwrap_err();
+        assert_eq!(err.field(), Some(CONFIG_VERSION_KEY));
+        assert!(err.to_string().contains("upgrade tf"));
+
+        let err = migrate_yaml("config_version: \"one\"\nproject_name: x\noutput_folder: y\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("non-negative integer"));
+    }
+
+    #[test]
+    fn test_migrate_config_file_rewrites_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, LEGACY).unwrap();
+
+        let planned = plan_migration(&path).unwrap();
+        assert!(!planned.is_up_to_date());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), LEGACY);
+
+        let report = migrate_config_file(&path).unwrap();
+        assert_eq!(report.changes, planned.changes);
+        let rewritten = std::fs::read_to_string(&path).unwrap();
+        assert!(rewritten.contains("config_version: 1"));
+        assert!(rewritten.contains("# Project settings"));
+        assert!(!dir.path().join("config.yaml.bak").exists());
+        assert!(migrate_config_file(&path).unwrap().is_up_to_date());
+    }
+
+    #[test]
+    fn test_toml_and_json_files_are_planned_but_not_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let toml = dir.path().join("config.toml");
+        let content = "project_name = \"demo\"\noutput_folder = \"./output\"\n";
+        std::fs::write(&toml, content).unwrap();
+
+        let planned = plan_migration(&toml).unwrap();
+        assert_eq!((planned.from_version, planned.to_version), (0, 1));
+        let err = migrate_config_file(&toml).unwrap_err();
+        assert_eq!(err.field(), Some(CONFIG_VERSION_KEY));
+        assert!(err.to_string().contains("config_version = 1"), "{}", err);
+        assert_eq!(std::fs::read_to_string(&toml).unwrap(), content);
+
+        let json = dir.path().join("config.json");
+        std::fs::write(
+            &json,
+            r#"{"config_version": 1, "project_name": "demo", "output_folder": "./output"}"#,
+        )
+        .unwrap();
+        assert!(migrate_config_file(&json).unwrap().is_up_to_date());
+    }
+}
@@ -0,0 +1,736 @@
+//! Single-value overrides from environment variables or code.
+//!
+//! Any leaf of the configuration can be overridden by its dotted path, without
+//! writing a new profile:
+//!
+//! - from the environment, with `TF__` followed by the path segments in upper
+//!   case separated by `__` (e.g. `TF__JIRA__ENDPOINT`, `TF__LLM__TIMEOUT_SECONDS`,
+//!   `TF__NETWORK__PROXY`)
+//! - from code, with [`ProjectConfig::set_override`]
+//!
+//! Paths follow the file: map entries are named by their key
+//! (`jira.scopes.sprint.window`) and list items by their index
+//! (`checklist.criteria.0.weight`). The accepted paths are derived from the JSON
+//! Schema of the configuration, see [`overridable_keys`]. `profiles`, `include`
+//! and `config_version` describe how the file is assembled and cannot be
+//! overridden. Environment variable names are upper-cased, so map keys reached
+//! through `TF__` must be lower case.
+//!
+//! Values are coerced to the field type (`"300"` for a number, `"true"` for a
+//! boolean, `"cloud"` for `llm.mode`, `"429,503"` for a list). An empty value
+//! unsets an optional field, restoring its default. The configuration is
+//! validated again after overrides are applied.
+//!
+//! # Layering
+//!
+//! Values are layered in this order, later layers winning:
+//!
+//! 1. the configuration file ([`load_config`](crate::load_config))
+//! 2. the selected profile ([`ProjectConfig::with_profile`])
+//! 3. environment overrides ([`ProjectConfig::apply_env_overrides`])
+//! 4. explicit overrides ([`ProjectConfig::set_override`])
+//!
+//! [`load_config_with_profile`](crate::load_config_with_profile) (and so
+//! [`discover_config`](crate::discover_config) and
+//! [`ConfigWatcher`](crate::ConfigWatcher)) applies the first three layers;
+//! after [`load_config`](crate::load_config) and
+//! [`ProjectConfig::with_profile`], call
+//! [`ProjectConfig::apply_env_overrides`] yourself.
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::load_config_with_profile;
+//!
+//! let mut config = load_config_with_profile(Path::new("config.yaml"), Some("ci"))?;
+//! config.set_override("llm.timeout_seconds", "300")?;
+//! config.set_override("jira.resilience.max_attempts", "5")?;
+//! # Ok::<(), tf_config::ConfigError>(())
+//! ```
+
+use serde_json::Value as Schema;
+use serde_yaml::{Mapping, Value};
+
+use crate::config::{friendly_serde_error, validate_config, ProjectConfig};
+use crate::error::ConfigError;
+use crate::provenance::ValueSource;
+use crate::schema::{config_schema, enum_values};
+
+/// Prefix of environment variables holding configuration overrides.
+pub const ENV_OVERRIDE_PREFIX: &str = "TF__";
+
+/// Top-level keys that cannot be overridden.
+const NON_OVERRIDABLE_KEYS: &[&str] = &["profiles", "include", "config_version"];
+
+/// Dotted paths accepted by [`ProjectConfig::set_override`], derived from the
+/// configuration schema.
+///
+/// Map entries appear as `<name>` and list items as `<index>`
+/// (e.g. `jira.scopes.<name>.window`, `checklist.criteria.<index>.weight`).
+pub fn overridable_keys() -> Vec<String> {
+    let schema = config_schema();
+    let mut keys = Vec::new();
+    collect_keys(&schema, &schema, "", &mut keys);
+    keys
+}
+
+impl ProjectConfig {
+    /// Override a single value by its dotted path and validate the result.
+    ///
+    /// Setting a field of a section that is not configured creates the section.
+    /// On error the configuration is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConfigError::InvalidValue`] if the key is unknown or the value cannot
+    ///   be coerced to the field type
+    /// - Any validation error caused by the new value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tf_config::ProjectConfig;
+    ///
+    /// let mut config: ProjectConfig =
+    ///     serde_yaml::from_str("project_name: \"demo\"\noutput_folder: \"./output\"\n").unwrap();
+    /// config.set_override("llm.timeout_seconds", "300").unwrap();
+    /// assert_eq!(config.llm.unwrap().timeout_seconds, 300);
+    /// ```
+    pub fn set_override(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        self.set_overrides([(key, value)])
+    }
+
+    /// Apply several overrides at once, validating only the final result.
+    ///
+    /// Useful when values depend on each other (e.g. switching `llm.mode` to
+    /// `cloud` together with the cloud settings). On error the configuration is
+    /// left unchanged.
+    pub fn set_overrides<I, K, V>(&mut self, overrides: I) -> Result<(), ConfigError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut updated = self.clone();
+        for (key, value) in overrides {
+            apply_override(
+                &mut updated,
+                key.as_ref(),
+                value.as_ref(),
+                ValueSource::Override,
+            )?;
+        }
+        validate_config(&updated)?;
+        *self = updated;
+        Ok(())
+    }
+
+    /// Apply `TF__SECTION__FIELD` overrides from the process environment.
+    ///
+    /// See [`ProjectConfig::apply_env_overrides_from`].
+    pub fn apply_env_overrides(&mut self) -> Result<(), ConfigError> {
+        self.apply_env_overrides_from(std::env::vars())
+    }
+
+    /// Apply `TF__SECTION__FIELD` overrides from the given variables.
+    ///
+    /// Variables without the [`ENV_OVERRIDE_PREFIX`] are ignored. Overrides are
+    /// applied in variable name order and validated together. Errors name the
+    /// environment variable that caused them.
+    pub fn apply_env_overrides_from<I>(&mut self, vars: I) -> Result<(), ConfigError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut overrides: Vec<(String, String, String)> = vars
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let key = env_var_to_key(&name)?;
+                Some((name, key, value))
+            })
+            .collect();
+        overrides.sort();
+
+        let mut updated = self.clone();
+        for (name, key, value) in &overrides {
+            apply_override(&mut updated, key, value, ValueSource::Env(name.clone()))
+                .map_err(|e| from_env_var(e, name))?;
+        }
+        validate_config(&updated)?;
+        *self = updated;
+        Ok(())
+    }
+}
+
+/// Map `TF__LLM__TIMEOUT_SECONDS` to `llm.timeout_seconds`.
+fn env_var_to_key(name: &str) -> Option<String> {
+    let rest = name.strip_prefix(ENV_OVERRIDE_PREFIX)?;
+    if rest.is_empty() {
+        return None;
+    }
+    Some(
+        rest.split("__")
+            .map(|segment| segment.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join("."),
+    )
+}
+
+/// Mention the environment variable in override errors.
+fn from_env_var(err: ConfigError, name: &str) -> ConfigError {
+    match err {
+        ConfigError::InvalidValue {
+            field,
+            reason,
+            hint,
+            location,
+        } => ConfigError::InvalidValue {
+            field,
+            reason: format!("{} (set by environment variable {})", reason, name),
+            hint,
+            location,
+        },
+        other => other,
+    }
+}
+
+/// Set one value without validating the configuration, recording its source.
+///
+/// The configuration goes through its serialized form: the leaf is set in the
+/// YAML document, coerced to the type the schema gives for `key`, and the
+/// document is deserialized back.
+fn apply_override(
+    config: &mut ProjectConfig,
+    key: &str,
+    value: &str,
+    source: ValueSource,
+) -> Result<(), ConfigError> {
+    let schema = config_schema();
+    let mut document =
+        serde_yaml::to_value(&*config).expect("a configuration always serializes to YAML");
+    set_leaf(&schema, &mut document, key, value)?;
+
+    let mut updated: ProjectConfig = serde_yaml::from_value(document).map_err(|e| {
+        friendly_serde_error(&e.to_string()).unwrap_or_else(|| {
+            ConfigError::invalid_value(
+                key,
+                format!("cannot be set to '{}': {}", value, e),
+                "a value of the field type",
+            )
+        })
+    })?;
+    // Fields skipped by serde are not part of the document
+    updated.include = config.include.take();
+    updated.active_profile = config.active_profile.take();
+    updated.source = config.source.take();
+    updated.base_dir = config.base_dir.take();
+    updated.provenance = std::mem::take(&mut config.provenance);
+
+    if value.is_empty() {
+        updated.provenance.remove(key);
+    } else {
+        updated.provenance.set(key, source);
+    }
+    // Other fields of a section created by the override hold their defaults
+    let mut provenance = std::mem::take(&mut updated.provenance);
+    provenance.record_defaults(&updated);
+    updated.provenance = provenance;
+    *config = updated;
+    Ok(())
+}
+
+/// Shape of a schema node, with `$ref`, single `allOf` and nullable `anyOf`
+/// wrappers resolved.
+enum Node<'a> {
+    /// Struct with named properties and the list of required ones
+    Struct(&'a serde_json::Map<String, Schema>, Vec<&'a str>),
+    /// Map with arbitrary keys
+    Map(&'a Schema),
+    /// List of items
+    List(&'a Schema),
+    /// Scalar value
+    Leaf(&'a Schema),
+}
+
+fn node<'a>(root: &'a Schema, mut schema: &'a Schema) -> Node<'a> {
+    loop {
+        if let Some(reference) = schema.get("$ref").and_then(Schema::as_str) {
+            let name = reference.trim_start_matches("#/definitions/");
+            schema = &root["definitions"][name];
+        } else if let Some([single]) = schema
+            .get("allOf")
+            .and_then(Schema::as_array)
+            .map(Vec::as_slice)
+        {
+            schema = single;
+        } else if let Some(variant) = schema
+            .get("anyOf")
+            .and_then(Schema::as_array)
+            .and_then(|variants| variants.iter().find(|v| v["type"] != "null"))
+        {
+            schema = variant;
+        } else {
+            break;
+        }
+    }
+    if let Some(properties) = schema.get("properties").and_then(Schema::as_object) {
+        let required = schema["required"]
+            .as_array()
+            .map(|names| names.iter().filter_map(Schema::as_str).collect())
+            .unwrap_or_default();
+        return Node::Struct(properties, required);
+    }
+    if has_type(schema, "array") {
+        return Node::List(&schema["items"]);
+    }
+    if has_type(schema, "object") && schema["additionalProperties"].is_object() {
+        return Node::Map(&schema["additionalProperties"]);
+    }
+    Node::Leaf(schema)
+}
+
+fn has_type(schema: &Schema, name: &str) -> bool {
+    match &schema["type"] {
+        Schema::String(t) => t == name,
+        Schema::Array(types) => types.iter().any(|t| t == name),
+        _ => false,
+    }
+}
+
+/// Walk `key` through the schema and the document, creating missing sections,
+/// and set the coerced value (or remove it when empty and optional).
+fn set_leaf(
+    root: &Schema,
+    document: &mut Value,
+    key: &str,
+    value: &str,
+) -> Result<(), ConfigError> {
+    let segments: Vec<&str> = key.split('.').collect();
+    let mut schema = root;
+    let mut target = document;
+
+    for (depth, segment) in segments.iter().enumerate() {
+        let prefix = segments[..depth].join(".");
+        let (child, optional) = match node(root, schema) {
+            Node::Struct(properties, required)
+                if !(depth == 0 && NON_OVERRIDABLE_KEYS.contains(segment)) =>
+            {
+                let Some(child) = properties.get(*segment) else {
+                    return Err(unknown_key(root, schema, key, &prefix));
+                };
+                (child, !required.contains(segment))
+            }
+            Node::Map(child) if !segment.is_empty() => (child, true),
+            Node::List(child) => {
+                let len = target.as_sequence().map_or(0, Vec::len);
+                if !segment.parse::<usize>().is_ok_and(|index| index <= len) {
+                    return Err(ConfigError::invalid_value(
+                        key,
+                        format!("has '{}' where a list index is expected", segment),
+                        format!("an index between 0 and {} for '{}'", len, prefix),
+                    ));
+                }
+                (child, false)
+            }
+            _ => return Err(unknown_key(root, schema, key, &prefix)),
+        };
+
+        let is_last = depth + 1 == segments.len();
+        if is_last {
+            return match node(root, child) {
+                Node::Leaf(leaf) => {
+                    let coerced = if value.is_empty() && optional {
+                        None
+                    } else {
+                        Some(coerce(leaf, key, value)?)
+                    };
+                    put(target, segment, coerced);
+                    Ok(())
+                }
+                Node::List(items) if matches!(node(root, items), Node::Leaf(_)) => {
+                    let coerced = if value.is_empty() && optional {
+                        None
+                    } else {
+                        let Node::Leaf(item) = node(root, items) else {
+                            unreachable!("checked by the match guard")
+                        };
+                        let values = value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|v| !v.is_empty())
+                            .map(|v| coerce(item, key, v))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        Some(Value::Sequence(values))
+                    };
+                    put(target, segment, coerced);
+                    Ok(())
+                }
+                _ => Err(unknown_key(root, child, key, key)),
+            };
+        }
+
+        let empty = match node(root, child) {
+            Node::List(_) => Value::Sequence(Vec::new()),
+            _ => Value::Mapping(Mapping::new()),
+        };
+        target = child_mut(target, segment, empty);
+        schema = child;
+    }
+    unreachable!("the last segment returns")
+}
+
+/// Child of a mapping or sequence, created as `empty` when missing or null.
+fn child_mut<'a>(parent: &'a mut Value, segment: &str, empty: Value) -> &'a mut Value {
+    if let Value::Sequence(items) = parent {
+        let index: usize = segment.parse().expect("list indexes are checked");
+        if index == items.len() {
+            items.push(empty);
+        }
+        return &mut items[index];
+    }
+    if !parent.is_mapping() {
+        *parent = Value::Mapping(Mapping::new());
+    }
+    let mapping = parent.as_mapping_mut().expect("just made a mapping");
+    let child = mapping
+        .entry(Value::String(segment.to_string()))
+        .or_insert(Value::Null);
+    if child.is_null() {
+        *child = empty;
+    }
+    child
+}
+
+/// Set or remove the leaf `segment` of `parent`.
+fn put(parent: &mut Value, segment: &str, value: Option<Value>) {
+    match (parent, value) {
+        (Value::Sequence(items), value) => {
+            let index: usize = segment.parse().expect("list indexes are checked");
+            let value = value.unwrap_or(Value::Null);
+            if index == items.len() {
+                items.push(value);
+            } else {
+                items[index] = value;
+            }
+        }
+        (parent, Some(value)) => {
+            if !parent.is_mapping() {
+                *parent = Value::Mapping(Mapping::new());
+            }
+            if let Some(mapping) = parent.as_mapping_mut() {
+                mapping.insert(Value::String(segment.to_string()), value);
+            }
+        }
+        (parent, None) => {
+            if let Some(mapping) = parent.as_mapping_mut() {
+                mapping.remove(segment);
+            }
+        }
+    }
+}
+
+/// Convert `value` to the scalar type of `leaf`.
+fn coerce(leaf: &Schema, key: &str, value: &str) -> Result<Value, ConfigError> {
+    let variants = enum_values(leaf);
+    if !variants.is_empty() {
+        return variants
+            .iter()
+            .find(|variant| variant.eq_ignore_ascii_case(value.trim()))
+            .map(|variant| Value::String(variant.to_string()))
+            .ok_or_else(|| {
+                ConfigError::invalid_value(
+                    key,
+                    format!("'{}' is not an accepted value", value),
+                    format!("one of: {}", variants.join(", ")),
+                )
+            });
+    }
+    if has_type(leaf, "boolean") {
+        return match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "off" | "0" => Ok(Value::Bool(false)),
+            _ => Err(ConfigError::invalid_value(
+                key,
+                format!("'{}' is not a valid boolean", value),
+                "true or false",
+            )),
+        };
+    }
+    if has_type(leaf, "integer") || has_type(leaf, "number") {
+        return value
+            .trim()
+            .parse::<u64>()
+            .map(|n| Value::Number(n.into()))
+            .map_err(|_| {
+                ConfigError::invalid_value(
+                    key,
+                    format!("'{}' is not a valid number", value),
+                    "a positive integer (e.g., 300)",
+                )
+            });
+    }
+    Ok(Value::String(value.to_string()))
+}
+
+/// Error for a path that does not name a leaf, listing the keys under `prefix`.
+fn unknown_key(root: &Schema, schema: &Schema, key: &str, prefix: &str) -> ConfigError {
+    let mut keys = Vec::new();
+    collect_keys(root, schema, prefix, &mut keys);
+    if keys.is_empty() {
+        collect_keys(root, root, "", &mut keys);
+    }
+    ConfigError::invalid_value(
+        key,
+        "is not an overridable configuration key",
+        format!("one of: {}", keys.join(", ")),
+    )
+}
+
+/// Append the leaf paths under `schema` to `keys`, prefixed by `prefix`.
+fn collect_keys(root: &Schema, schema: &Schema, prefix: &str, keys: &mut Vec<String>) {
+    let join = |segment: &str| {
+        if prefix.is_empty() {
+            segment.to_string()
+        } else {
+            format!("{}.{}", prefix, segment)
+        }
+    };
+    match node(root, schema) {
+        Node::Struct(properties, _) => {
+            for (name, child) in properties {
+                if prefix.is_empty() && NON_OVERRIDABLE_KEYS.contains(&name.as_str()) {
+                    continue;
+                }
+                collect_keys(root, child, &join(name), keys);
+            }
+        }
+        Node::Map(child) => collect_keys(root, child, &join("<name>"), keys),
+        Node::List(items) if !matches!(node(root, items), Node::Leaf(_)) => {
+            collect_keys(root, items, &join("<index>"), keys)
+        }
+        _ => keys.push(prefix.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LlmMode;
+
+    fn parse(yaml: &str) -> ProjectConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    const BASE: &str = r#"
+project_name: "demo"
+output_folder: "./output"
+jira:
+  endpoint: "https://jira.example.com"
+  token: "file-token"
+llm:
+  mode: "local"
+  local_endpoint: "http://localhost:11434"
+"#;
+
+    #[test]
+    fn test_set_override_coerces_types() {
+        let mut config = parse(BASE);
+        config.set_override("llm.timeout_seconds", "300").unwrap();
+        config.set_override("llm.mode", "Auto").unwrap();
+        config
+            .set_override("jira.endpoint", "https://jira.ci.example.com")
+            .unwrap();
+
+        let llm = config.llm.as_ref().unwrap();
+        assert_eq!(llm.timeout_seconds, 300);
+        assert_eq!(llm.mode, LlmMode::Auto);
+        assert_eq!(config.jira.unwrap().endpoint, "https://jira.ci.example.com");
+    }
+
+    #[test]
+    fn test_set_override_rejects_bad_values_and_keeps_config() {
+        let mut config = parse(BASE);
+
+        let err = config
+            .set_override("llm.timeout_seconds", "soon")
+            .unwrap_err();
+        assert!(err.to_string().contains("not a valid number"));
+
+        // Coerces fine but fails validation
+        let err = config.set_override("llm.timeout_seconds", "0").unwrap_err();
+        assert_eq!(err.field(), Some("llm.timeout_seconds"));
+        assert_eq!(config.llm.as_ref().unwrap().timeout_seconds, 120);
+
+        let err = config.set_override("jira.endpont", "x").unwrap_err();
+        assert!(err.to_string().contains("jira.endpoint"));
+    }
+
+    #[test]
+    fn test_set_overrides_validates_once() {
+        let mut config = parse(BASE);
+        config
+            .set_overrides([
+                ("llm.mode", "cloud"),
+                ("llm.cloud_enabled", "true"),
+                ("llm.cloud_endpoint", "https://api.example.com/v1"),
+                ("llm.cloud_model", "gpt-4o-mini"),
+                ("llm.api_key", "sk-ci"),
+            ])
+            .unwrap();
+        assert_eq!(config.llm.unwrap().mode, LlmMode::Cloud);
+    }
+
+    #[test]
+    fn test_env_overrides_map_variable_names_to_keys() {
+        let mut config = parse(BASE);
+        config
+            .apply_env_overrides_from(vars(&[
+                ("TF__JIRA__ENDPOINT", "https://jira.env.example.com"),
+                ("TF__LLM__TIMEOUT_SECONDS", "45"),
+                ("TF__SQUASH__ENDPOINT", "https://squash.env.example.com"),
+                ("TF__JIRA__TOKEN", ""),
+                ("PATH", "/usr/bin"),
+            ]))
+            .unwrap();
+
+        let jira = config.jira.as_ref().unwrap();
+        assert_eq!(jira.endpoint, "https://jira.env.example.com");
+        assert!(jira.token.is_none());
+        assert_eq!(config.llm.as_ref().unwrap().timeout_seconds, 45);
+        assert_eq!(
+            config.squash.as_ref().unwrap().endpoint,
+            "https://squash.env.example.com"
+        );
+    }
+
+    #[test]
+    fn test_env_override_errors_name_the_variable() {
+        let mut config = parse(BASE);
+        let err = config
+            .apply_env_overrides_from(vars(&[("TF__LLM__CLOUD_ENABLED", "maybe")]))
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("llm.cloud_enabled"), "{}", message);
+        assert!(message.contains("TF__LLM__CLOUD_ENABLED"), "{}", message);
+    }
+
+    #[test]
+    fn test_overrides_reach_every_section() {
+        let mut config = parse(BASE);
+        config
+            .set_overrides([
+                ("network.proxy", "http://proxy.example.com:3128"),
+                ("network.no_proxy", "localhost, .internal"),
+                ("jira.resilience.max_attempts", "5"),
+                ("jira.resilience.retry_on", "429,503"),
+                ("jira.scopes.sprint.projects", "PAY"),
+                ("jira.scopes.sprint.window", "last_7d"),
+                ("anonymization.strict", "true"),
+            ])
+            .unwrap();
+        config
+            .apply_env_overrides_from(vars(&[("TF__LLM__RESILIENCE__FALLBACK", "Cache")]))
+            .unwrap();
+
+        let network = config.network.as_ref().unwrap();
+        assert_eq!(
+            network.no_proxy.as_deref(),
+            Some(&["localhost".to_string(), ".internal".to_string()][..])
+        );
+        let resilience = config.jira.as_ref().unwrap().resilience.as_ref().unwrap();
+        assert_eq!(resilience.max_attempts, 5);
+        assert_eq!(resilience.retry_on, [429, 503]);
+        assert_eq!(resilience.base_delay_ms, 500);
+        assert_eq!(
+            config
+                .jira
+                .as_ref()
+                .unwrap()
+                .scope("sprint")
+                .unwrap()
+                .to_jql(),
+            "project in (PAY) AND updated >= -7d"
+        );
+        assert!(config.anonymization.as_ref().unwrap().strict);
+        assert_eq!(
+            config
+                .llm
+                .as_ref()
+                .unwrap()
+                .resilience
+                .as_ref()
+                .unwrap()
+                .fallback,
+            crate::FallbackMode::Cache
+        );
+        assert_eq!(
+            config.provenance.get("network.proxy"),
+            Some(&ValueSource::Override)
+        );
+
+        // An empty value restores the default
+        config.set_override("jira.resilience.retry_on", "").unwrap();
+        let resilience = config.jira.as_ref().unwrap().resilience.as_ref().unwrap();
+        assert_eq!(resilience.retry_on, [429, 500, 502, 503, 504]);
+
+        let err = config.set_override("network.proxi", "x").unwrap_err();
+        assert!(err.to_string().contains("network.proxy"), "{}", err);
+        let err = config
+            .set_override("profiles.dev.output_folder", "x")
+            .unwrap_err();
+        assert!(err.to_string().contains("is not an overridable"), "{}", err);
+        let err = config.set_override("jira.resilience", "5").unwrap_err();
+        assert!(
+            err.to_string().contains("jira.resilience.max_attempts"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_overridable_keys_follow_the_schema() {
+        let keys = overridable_keys();
+        for key in [
+            "project_name",
+            "default_profile",
+            "llm.mode",
+            "network.proxy",
+            "jira.resilience.max_attempts",
+            "jira.scopes.<name>.window",
+            "jira.fields.<name>",
+            "checklist.thresholds.go",
+            "checklist.criteria.<index>.weight",
+            "anonymization.strict",
+            "anonymization.allow.<name>",
+        ] {
+            assert!(
+                keys.iter().any(|k| k == key),
+                "{} missing from {:?}",
+                key,
+                keys
+            );
+        }
+        assert!(!keys
+            .iter()
+            .any(|k| k.starts_with("profiles") || k == "include"));
+    }
+
+    #[test]
+    fn test_env_var_to_key() {
+        assert_eq!(
+            env_var_to_key("TF__LLM__TIMEOUT_SECONDS").as_deref(),
+            Some("llm.timeout_seconds")
+        );
+        assert_eq!(
+            env_var_to_key("TF__OUTPUT_FOLDER").as_deref(),
+            Some("output_folder")
+        );
+        assert_eq!(env_var_to_key("TF_CONFIG"), None);
+        assert_eq!(env_var_to_key("TF__"), None);
+    }
+}
@@ -28,8 +28,37 @@
 //!   staging:
 //!     jira:
 //!       endpoint: "https://jira.staging.example.com"
+//!   preprod:
+//!     extends: staging
+//!     output_folder: "./preprod-output"
 //! ```
 //!
+//! # Merge Strategies
+//!
+//! A section set in a profile replaces the base section as a whole unless
+//! `merge: deep` is given, either for the whole profile or for one section:
+//!
+//! ```yaml
+//! profiles:
+//!   dev:
+//!     merge: deep            # every section of this profile is deep-merged...
+//!     llm:
+//!       local_model: "codellama:13b"
+//!     jira:
+//!       merge: replace       # ...except this one, which replaces the base section
+//!       endpoint: "https://jira.dev.example.com"
+//! ```
+//!
+//! Deep merge cannot unset a base value; use `merge: replace` for that.
+//!
+//! # Inheritance
+//!
+//! A profile may name a parent profile with `extends`. The chain is resolved by
+//! [`ProjectConfig::with_profile`](crate::ProjectConfig::with_profile): the base
+//! configuration comes first, then each ancestor from the outermost one, then the
+//! requested profile. A chain that loops back on itself is rejected with
+//! [`ConfigError::ProfileCycle`](crate::ConfigError::ProfileCycle).
+//!
 //! # Security
 //!
 //! All sensitive fields (tokens, passwords, API keys) are automatically redacted
@@ -38,12 +67,13 @@
 //! Use the [`Redact`] trait's `.redacted()` method for safe logging:
 //!
 //! ```
-//! use tf_config::{ProfileOverride, JiraConfig, Redact};
+//! use tf_config::{JiraOverride, ProfileOverride, Redact};
 //!
 //! let profile = ProfileOverride {
-//!     jira: Some(JiraConfig {
-//!         endpoint: "https://jira.example.com".to_string(),
+//!     jira: Some(JiraOverride {
+//!         endpoint: Some("https://jira.example.com".to_string()),
 //!         token: Some("secret-token".to_string()),
+//!         ..Default::default()
 //!     }),
 //!     ..Default::default()
 //! };
@@ -54,8 +84,17 @@
 //! assert!(!safe_output.contains("secret-token"));
 //! ```
 
-use crate::config::{JiraConfig, LlmConfig, Redact, SquashConfig, TemplatesConfig};
-use serde::Deserialize;
+use crate::checklist::{ChecklistConfig, ChecklistCriterion, ScoreThresholds};
+use crate::config::{
+    default_max_tokens, default_timeout_seconds, redact_url_sensitive_params, JiraConfig,
+    LlmConfig, LlmMode, Redact, SquashConfig, TemplatesConfig,
+};
+use crate::network::NetworkConfig;
+use crate::resilience::ResilienceConfig;
+use crate::scopes::JiraScope;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Profile identifier type alias for clarity.
@@ -66,6 +105,11 @@ pub type ProfileId = String;
 /// Each field is optional - only specified fields will override the base configuration.
 /// Fields set to `None` preserve the base configuration value (partial override pattern).
 ///
+/// Sections (`jira`, `squash`, `llm`, `templates`, `checklist`) are partial structs. By default a
+/// section replaces the whole base section; with [`MergeStrategy::Deep`] only the
+/// fields it sets override the base, so a profile changing `llm.local_model` does
+/// not need to repeat the rest of the LLM section.
+///
 /// # Security
 ///
 /// This struct implements a custom `Debug` trait that redacts sensitive information
@@ -74,50 +118,78 @@ pub type ProfileId = String;
 /// # Example
 ///
 /// ```
-/// use tf_config::{ProfileOverride, JiraConfig};
+/// use tf_config::{JiraOverride, MergeStrategy, ProfileOverride};
 ///
 /// let profile = ProfileOverride {
 ///     output_folder: Some("./dev-output".to_string()),
-///     jira: Some(JiraConfig {
-///         endpoint: "https://jira.dev.example.com".to_string(),
-///         token: Some("secret-token".to_string()),
+///     merge: Some(MergeStrategy::Deep),
+///     jira: Some(JiraOverride {
+///         endpoint: Some("https://jira.dev.example.com".to_string()),
+///         ..Default::default()
 ///     }),
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Clone, Default, PartialEq, Deserialize)]
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
+#[schemars(
+    description = "Configuration overrides for a specific profile. Only the fields that are set override the base configuration."
+)]
 pub struct ProfileOverride {
+    /// Name of a parent profile whose overrides are applied first.
+    ///
+    /// Chains are resolved from the outermost ancestor down to this profile, so
+    /// `preprod` extending `staging` only needs to list what differs from `staging`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<ProfileId>,
+
+    /// Default merge strategy for the sections of this profile.
+    ///
+    /// Each section may override it with its own `merge` key. When neither is
+    /// set, sections use [`MergeStrategy::Replace`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<MergeStrategy>,
+
     /// Override for output folder path.
     ///
     /// When set, replaces the base `output_folder` configuration.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub output_folder: Option<String>,
 
     /// Override for Jira integration configuration.
     ///
-    /// When set, completely replaces the base Jira configuration.
-    /// To partially update Jira settings, specify all desired values.
-    #[serde(default)]
-    pub jira: Option<JiraConfig>,
+    /// Replaces or deep-merges the base Jira configuration depending on the
+    /// effective [`MergeStrategy`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira: Option<JiraOverride>,
 
     /// Override for Squash integration configuration.
     ///
-    /// When set, completely replaces the base Squash configuration.
-    #[serde(default)]
-    pub squash: Option<SquashConfig>,
+    /// Replaces or deep-merges the base Squash configuration depending on the
+    /// effective [`MergeStrategy`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub squash: Option<SquashOverride>,
 
     /// Override for LLM configuration.
     ///
-    /// When set, completely replaces the base LLM configuration.
-    #[serde(default)]
-    pub llm: Option<LlmConfig>,
+    /// Replaces or deep-merges the base LLM configuration depending on the
+    /// effective [`MergeStrategy`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm: Option<LlmOverride>,
 
     /// Override for template file paths.
     ///
-    /// When set, completely replaces the base templates configuration.
-    #[serde(default)]
-    pub templates: Option<TemplatesConfig>,
+    /// Replaces or deep-merges the base templates configuration depending on
+    /// the effective [`MergeStrategy`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates: Option<TemplatesOverride>,
+
+    /// Override for the testability checklist.
+    ///
+    /// Replaces or deep-merges the base checklist depending on the effective
+    /// [`MergeStrategy`]; `criteria` is always replaced as a whole list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<ChecklistOverride>,
 }
 
 /// Custom Debug implementation that redacts sensitive information.
@@ -127,11 +199,14 @@ pub struct ProfileOverride {
 impl fmt::Debug for ProfileOverride {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ProfileOverride")
+            .field("extends", &self.extends)
+            .field("merge", &self.merge)
             .field("output_folder", &self.output_folder)
-            .field("jira", &self.jira) // JiraConfig has its own redacting Debug
-            .field("squash", &self.squash) // SquashConfig has its own redacting Debug
-            .field("llm", &self.llm) // LlmConfig has its own redacting Debug
+            .field("jira", &self.jira) // JiraOverride has its own redacting Debug
+            .field("squash", &self.squash) // SquashOverride has its own redacting Debug
+            .field("llm", &self.llm) // LlmOverride has its own redacting Debug
             .field("templates", &self.templates)
+            .field("checklist", &self.checklist)
             .finish()
     }
 }
@@ -142,5 +217,482 @@ impl Redact for ProfileOverride {
     }
 }
 
+/// How a profile section is combined with the base section.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeStrategy {
+    /// The profile section replaces the whole base section; fields it does not
+    /// set take their defaults. This is the historical behavior.
+    #[default]
+    Replace,
+    /// Only the fields set in the profile section override the base section;
+    /// every other field is inherited.
+    Deep,
+}
+
+impl fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeStrategy::Replace => write!(f, "replace"),
+            MergeStrategy::Deep => write!(f, "deep"),
+        }
+    }
+}
+
+/// Partial Jira configuration used in profiles.
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JiraOverride {
+    /// Merge strategy for this section, overriding the profile's `merge`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<MergeStrategy>,
+
+    /// Jira server endpoint URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+
+    /// API token (sensitive - redacted in Debug output)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    /// Network settings overriding the shared `network` section
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Retry and fallback policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resilience: Option<ResilienceConfig>,
+
+    /// Named import scopes, replacing the base scopes as a whole
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<BTreeMap<String, JiraScope>>,
+
+    /// Jira field ids of logical fields, replacing the base mappings as a whole
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, String>>,
+}
+
+/// Partial Squash configuration used in profiles.
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SquashOverride {
+    /// Merge strategy for this section, overriding the profile's `merge`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<MergeStrategy>,
+
+    /// Squash server endpoint URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+
+    /// Username for authentication
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Password (sensitive - redacted in Debug output)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// Network settings overriding the shared `network` section
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Retry and fallback policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resilience: Option<ResilienceConfig>,
+}
+
+/// Partial LLM configuration used in profiles.
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LlmOverride {
+    /// Merge strategy for this section, overriding the profile's `merge`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<MergeStrategy>,
+
+    /// LLM mode: auto, local, or cloud
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LlmMode>,
+
+    /// Local LLM endpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_endpoint: Option<String>,
+
+    /// Local model name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_model: Option<String>,
+
+    /// Whether cloud LLM is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_enabled: Option<bool>,
+
+    /// Cloud LLM endpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_endpoint: Option<String>,
+
+    /// Cloud model name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_model: Option<String>,
+
+    /// API key for cloud LLM (sensitive - redacted in Debug output)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+
+    /// Request timeout in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u32>,
+
+    /// Maximum tokens for LLM response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+
+    /// Network settings overriding the shared `network` section
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Retry and fallback policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resilience: Option<ResilienceConfig>,
+}
+
+/// Partial templates configuration used in profiles.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TemplatesOverride {
+    /// Merge strategy for this section, overriding the profile's `merge`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<MergeStrategy>,
+
+    /// Path to CR (compte-rendu/daily report) template file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cr: Option<String>,
+
+    /// Path to PPT (PowerPoint presentation) template file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ppt: Option<String>,
+
+    /// Path to anomaly report template file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anomaly: Option<String>,
+}
+
+/// Partial testability checklist used in profiles.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ChecklistOverride {
+    /// Merge strategy for this section, overriding the profile's `merge`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<MergeStrategy>,
+
+    /// Criteria, replacing the base list as a whole
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<Vec<ChecklistCriterion>>,
+
+    /// Score thresholds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thresholds: Option<ThresholdsOverride>,
+}
+
+/// Partial score thresholds used in profiles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ThresholdsOverride {
+    /// Minimum score for Go
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub go: Option<u32>,
+
+    /// Minimum score for Clarify
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clarify: Option<u32>,
+}
+
+impl JiraOverride {
+    /// Combine this section with `base` using the section's strategy, or
+    /// `default` when the section does not set one.
+    pub(crate) fn merge_onto(
+        &self,
+        base: Option<&JiraConfig>,
+        default: MergeStrategy,
+    ) -> JiraConfig {
+        let mut merged = match (self.merge.unwrap_or(default), base) {
+            (MergeStrategy::Deep, Some(base)) => base.clone(),
+            _ => JiraConfig {
+                endpoint: String::new(),
+                token: None,
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            },
+        };
+        overlay(&mut merged.endpoint, &self.endpoint);
+        overlay_opt(&mut merged.token, &self.token);
+        overlay_opt(&mut merged.network, &self.network);
+        overlay_opt(&mut merged.resilience, &self.resilience);
+        overlay_opt(&mut merged.scopes, &self.scopes);
+        overlay_opt(&mut merged.fields, &self.fields);
+        merged
+    }
+}
+
+impl SquashOverride {
+    /// Combine this section with `base` using the section's strategy, or
+    /// `default` when the section does not set one.
+    pub(crate) fn merge_onto(
+        &self,
+        base: Option<&SquashConfig>,
+        default: MergeStrategy,
+    ) -> SquashConfig {
+        let mut merged = match (self.merge.unwrap_or(default), base) {
+            (MergeStrategy::Deep, Some(base)) => base.clone(),
+            _ => SquashConfig {
+                endpoint: String::new(),
+                username: None,
+                password: None,
+                network: None,
+                resilience: None,
+            },
+        };
+        overlay(&mut merged.endpoint, &self.endpoint);
+        overlay_opt(&mut merged.username, &self.username);
+        overlay_opt(&mut merged.password, &self.password);
+        overlay_opt(&mut merged.network, &self.network);
+        overlay_opt(&mut merged.resilience, &self.resilience);
+        merged
+    }
+}
+
+impl LlmOverride {
+    /// Combine this section with `base` using the section's strategy, or
+    /// `default` when the section does not set one.
+    pub(crate) fn merge_onto(&self, base: Option<&LlmConfig>, default: MergeStrategy) -> LlmConfig {
+        let mut merged = match (self.merge.unwrap_or(default), base) {
+            (MergeStrategy::Deep, Some(base)) => base.clone(),
+            _ => LlmConfig {
+                mode: LlmMode::default(),
+                local_endpoint: None,
+                local_model: None,
+                cloud_enabled: false,
+                cloud_endpoint: None,
+                cloud_model: None,
+                api_key: None,
+                timeout_seconds: default_timeout_seconds(),
+                max_tokens: default_max_tokens(),
+                network: None,
+                resilience: None,
+            },
+        };
+        overlay(&mut merged.mode, &self.mode);
+        overlay_opt(&mut merged.local_endpoint, &self.local_endpoint);
+        overlay_opt(&mut merged.local_model, &self.local_model);
+        overlay(&mut merged.cloud_enabled, &self.cloud_enabled);
+        overlay_opt(&mut merged.cloud_endpoint, &self.cloud_endpoint);
+        overlay_opt(&mut merged.cloud_model, &self.cloud_model);
+        overlay_opt(&mut merged.api_key, &self.api_key);
+        overlay(&mut merged.timeout_seconds, &self.timeout_seconds);
+        overlay(&mut merged.max_tokens, &self.max_tokens);
+        overlay_opt(&mut merged.network, &self.network);
+        overlay_opt(&mut merged.resilience, &self.resilience);
+        merged
+    }
+}
+
+impl TemplatesOverride {
+    /// Combine this section with `base` using the section's strategy, or
+    /// `default` when the section does not set one.
+    pub(crate) fn merge_onto(
+        &self,
+        base: Option<&TemplatesConfig>,
+        default: MergeStrategy,
+    ) -> TemplatesConfig {
+        let mut merged = match (self.merge.unwrap_or(default), base) {
+            (MergeStrategy::Deep, Some(base)) => base.clone(),
+            _ => TemplatesConfig {
+                cr: None,
+                ppt: None,
+                anomaly: None,
+            },
+        };
+        overlay_opt(&mut merged.cr, &self.cr);
+        overlay_opt(&mut merged.ppt, &self.ppt);
+        overlay_opt(&mut merged.anomaly, &self.anomaly);
+        merged
+    }
+}
+
+impl ChecklistOverride {
+    /// Combine this section with `base` using the section's strategy, or
+    /// `default` when the section does not set one.
+    pub(crate) fn merge_onto(
+        &self,
+        base: Option<&ChecklistConfig>,
+        default: MergeStrategy,
+    ) -> ChecklistConfig {
+        let mut merged = match (self.merge.unwrap_or(default), base) {
+            (MergeStrategy::Deep, Some(base)) => base.clone(),
+            _ => ChecklistConfig {
+                criteria: Vec::new(),
+                thresholds: ScoreThresholds::default(),
+            },
+        };
+        overlay(&mut merged.criteria, &self.criteria);
+        if let Some(thresholds) = &self.thresholds {
+            overlay(&mut merged.thresholds.go, &thresholds.go);
+            overlay(&mut merged.thresholds.clarify, &thresholds.clarify);
+        }
+        merged
+    }
+}
+
+/// Overwrite `target` with `value` when the profile sets it.
+fn overlay<T: Clone>(target: &mut T, value: &Option<T>) {
+    if let Some(v) = value {
+        *target = v.clone();
+    }
+}
+
+/// Overwrite an optional `target` with `value` when the profile sets it.
+fn overlay_opt<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+    if let Some(v) = value {
+        *target = Some(v.clone());
+    }
+}
+
+// Full sections convert to overrides that set every field, so code building
+// profiles from complete configurations keeps working.
+
+impl From<JiraConfig> for JiraOverride {
+    fn from(config: JiraConfig) -> Self {
+        JiraOverride {
+            merge: None,
+            endpoint: Some(config.endpoint),
+            token: config.token,
+            network: config.network,
+            resilience: config.resilience,
+            scopes: config.scopes,
+            fields: config.fields,
+        }
+    }
+}
+
+impl From<SquashConfig> for SquashOverride {
+    fn from(config: SquashConfig) -> Self {
+        SquashOverride {
+            merge: None,
+            endpoint: Some(config.endpoint),
+            username: config.username,
+            password: config.password,
+            network: config.network,
+            resilience: config.resilience,
+        }
+    }
+}
+
+impl From<LlmConfig> for LlmOverride {
+    fn from(config: LlmConfig) -> Self {
+        LlmOverride {
+            merge: None,
+            mode: Some(config.mode),
+            local_endpoint: config.local_endpoint,
+            local_model: config.local_model,
+            cloud_enabled: Some(config.cloud_enabled),
+            cloud_endpoint: config.cloud_endpoint,
+            cloud_model: config.cloud_model,
+            api_key: config.api_key,
+            timeout_seconds: Some(config.timeout_seconds),
+            max_tokens: Some(config.max_tokens),
+            network: config.network,
+            resilience: config.resilience,
+        }
+    }
+}
+
+impl From<TemplatesConfig> for TemplatesOverride {
+    fn from(config: TemplatesConfig) -> Self {
+        TemplatesOverride {
+            merge: None,
+            cr: config.cr,
+            ppt: config.ppt,
+            anomaly: config.anomaly,
+        }
+    }
+}
+
+impl From<ChecklistConfig> for ChecklistOverride {
+    fn from(config: ChecklistConfig) -> Self {
+        ChecklistOverride {
+            merge: None,
+            criteria: Some(config.criteria),
+            thresholds: Some(ThresholdsOverride {
+                go: Some(config.thresholds.go),
+                clarify: Some(config.thresholds.clarify),
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for JiraOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JiraOverride")
+            .field("merge", &self.merge)
+            .field(
+                "endpoint",
+                &self.endpoint.as_deref().map(redact_url_sensitive_params),
+            )
+            .field("token", &self.token.as_ref().map(|_| "[REDACTED]"))
+            .field("network", &self.network)
+            .field("resilience", &self.resilience)
+            .field("scopes", &self.scopes)
+            .field("fields", &self.fields)
+            .finish()
+    }
+}
+
+impl fmt::Debug for SquashOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SquashOverride")
+            .field("merge", &self.merge)
+            .field(
+                "endpoint",
+                &self.endpoint.as_deref().map(redact_url_sensitive_params),
+            )
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "[REDACTED]"))
+            .field("network", &self.network)
+            .field("resilience", &self.resilience)
+            .finish()
+    }
+}
+
+impl fmt::Debug for LlmOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LlmOverride")
+            .field("merge", &self.merge)
+            .field("mode", &self.mode)
+            .field(
+                "local_endpoint",
+                &self
+                    .local_endpoint
+                    .as_deref()
+                    .map(redact_url_sensitive_params),
+            )
+            .field("local_model", &self.local_model)
+            .field("cloud_enabled", &self.cloud_enabled)
+            .field(
+                "cloud_endpoint",
+                &self
+                    .cloud_endpoint
+                    .as_deref()
+                    .map(redact_url_sensitive_params),
+            )
+            .field("cloud_model", &self.cloud_model)
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("timeout_seconds", &self.timeout_seconds)
+            .field("max_tokens", &self.max_tokens)
+            .field("network", &self.network)
+            .field("resilience", &self.resilience)
+            .finish()
+    }
+}
+
 // Note: Unit tests for ProfileOverride are in tests/profile_unit_tests.rs
 // to avoid duplication and keep the source file focused on implementation.
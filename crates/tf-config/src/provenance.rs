@@ -0,0 +1,367 @@
+//! Provenance of the effective configuration values.
+//!
+//! Once profiles and overrides are layered on top of the file, every leaf value
+//! of a [`ProjectConfig`] records where it came from in
+//! [`ProjectConfig::provenance`]:
+//!
+//! | Source | Set by |
+//! |--------|--------|
+//! | [`ValueSource::File`] | [`load_config`](crate::load_config) for keys present in the file |
+//! | [`ValueSource::Default`] | built-in defaults (e.g. `llm.timeout_seconds`) |
+//! | [`ValueSource::Profile`] | [`ProjectConfig::with_profile`] |
+//! | [`ValueSource::Env`] | [`ProjectConfig::apply_env_overrides`] |
+//! | [`ValueSource::Override`] | [`ProjectConfig::set_override`] |
+//!
+//! [`load_config_with_profile`](crate::load_config_with_profile) also records
+//! how it selected the profile under `active_profile`: `Override` for an
+//! explicit name, `Env("TF_PROFILE")`, or `File` for `default_profile`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::{load_config, ValueSource};
+//!
+//! let config = load_config(Path::new("config.yaml"))?.with_profile("dev")?;
+//! if let Some(source) = config.provenance.get("llm.timeout_seconds") {
+//!     println!("llm.timeout_seconds comes from {}", source);
+//! }
+//! println!("{}", config.active_profile_summary_with_sources());
+//! # Ok::<(), tf_config::ConfigError>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Serialize;
+use serde_yaml::Value;
+
+use crate::config::ProjectConfig;
+use crate::profiles::{MergeStrategy, ProfileOverride};
+
+/// Where an effective configuration value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueSource {
+    /// The base section of the configuration file
+    File,
+    /// A built-in default, applied because the key is absent
+    Default,
+    /// A profile, by name (empty for profiles applied with
+    /// [`ProjectConfig::apply_profile`], which have no name)
+    Profile(String),
+    /// A `TF__SECTION__FIELD` environment variable, by name
+    Env(String),
+    /// An explicit [`ProjectConfig::set_override`] call
+    Override,
+}
+
+impl fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueSource::File => write!(f, "file"),
+            ValueSource::Default => write!(f, "default"),
+            ValueSource::Profile(name) if name.is_empty() => write!(f, "profile"),
+            ValueSource::Profile(name) => write!(f, "profile '{}'", name),
+            ValueSource::Env(variable) => write!(f, "env {}", variable),
+            ValueSource::Override => write!(f, "override"),
+        }
+    }
+}
+
+/// Source of every leaf value, keyed by dotted path (e.g. `llm.timeout_seconds`).
+///
+/// Map entries are keyed by name and list items by index, as in the file
+/// (e.g. `jira.scopes.sprint.window`, `checklist.criteria.0.weight`); lists of
+/// plain values such as `network.no_proxy` are a single leaf. Only values that
+/// are set are recorded: an unset optional field has no entry.
+/// Configurations built in code start with an empty map.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Provenance {
+    sources: BTreeMap<String, ValueSource>,
+}
+
+/// LLM fields that always hold a value, from the file or from their default.
+pub(crate) const LLM_DEFAULTED_FIELDS: &[&str] =
+    &["mode", "cloud_enabled", "timeout_seconds", "max_tokens"];
+
+/// Checklist fields that always hold a value, from the file or from their default.
+const CHECKLIST_DEFAULTED_FIELDS: &[&str] = &["thresholds.go", "thresholds.clarify"];
+
+impl Provenance {
+    /// Source of the value at `field`, if the value is set and tracked.
+    pub fn get(&self, field: &str) -> Option<&ValueSource> {
+        self.sources.get(field)
+    }
+
+    /// Record the source of the value at `field`.
+    pub fn set(&mut self, field: impl Into<String>, source: ValueSource) {
+        self.sources.insert(field.into(), source);
+    }
+
+    /// Forget the source of the value at `field` (when it is unset).
+    pub fn remove(&mut self, field: &str) {
+        self.sources.remove(field);
+    }
+
+    /// Iterate over `(field, source)` pairs in field order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ValueSource)> {
+        self.sources.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Whether no source is recorded.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Provenance of a configuration freshly parsed from a file: keys present in
+    /// the file come from it, other set values come from defaults.
+    pub(crate) fn from_file(config: &ProjectConfig, in_file: impl Fn(&str) -> bool) -> Self {
+        let mut provenance = Provenance::default();
+        for field in set_leaves(config) {
+            let source = if in_file(&field) {
+                ValueSource::File
+            } else {
+                ValueSource::Default
+            };
+            provenance.set(field, source);
+        }
+        provenance
+    }
+
+    /// Record leaves of `config` that have no source yet as defaults, e.g. the
+    /// other fields of a section created by an override.
+    pub(crate) fn record_defaults(&mut self, config: &ProjectConfig) {
+        for field in set_leaves(config) {
+            self.sources.entry(field).or_insert(ValueSource::Default);
+        }
+    }
+
+    /// Update the provenance for a profile applied onto `base`.
+    pub(crate) fn record_profile(
+        &mut self,
+        base: &ProjectConfig,
+        profile: &ProfileOverride,
+        name: &str,
+    ) {
+        let source = ValueSource::Profile(name.to_string());
+        let default = profile.merge.unwrap_or_default();
+
+        if profile.output_folder.is_some() {
+            self.set("output_folder", source.clone());
+        }
+        if let Some(jira) = &profile.jira {
+            let fields = [
+                ("endpoint", jira.endpoint.is_some()),
+                ("token", jira.token.is_some()),
+            ];
+            let replaced = replaces(jira.merge, default, base.jira.is_some());
+            self.record_section("jira", &fields, replaced, &source);
+            self.record_block("jira.network", jira.network.as_ref(), replaced, &source);
+            self.record_block(
+                "jira.resilience",
+                jira.resilience.as_ref(),
+                replaced,
+                &source,
+            );
+            self.record_block("jira.scopes", jira.scopes.as_ref(), replaced, &source);
+            self.record_block("jira.fields", jira.fields.as_ref(), replaced, &source);
+        }
+        if let Some(squash) = &profile.squash {
+            let fields = [
+                ("endpoint", squash.endpoint.is_some()),
+                ("username", squash.username.is_some()),
+                ("password", squash.password.is_some()),
+            ];
+            let replaced = replaces(squash.merge, default, base.squash.is_some());
+            self.record_section("squash", &fields, replaced, &source);
+            self.record_block("squash.network", squash.network.as_ref(), replaced, &source);
+            self.record_block(
+                "squash.resilience",
+                squash.resilience.as_ref(),
+                replaced,
+                &source,
+            );
+        }
+        if let Some(llm) = &profile.llm {
+            let fields = [
+                ("mode", llm.mode.is_some()),
+                ("local_endpoint", llm.local_endpoint.is_some()),
+                ("local_model", llm.local_model.is_some()),
+                ("cloud_enabled", llm.cloud_enabled.is_some()),
+                ("cloud_endpoint", llm.cloud_endpoint.is_some()),
+                ("cloud_model", llm.cloud_model.is_some()),
+                ("api_key", llm.api_key.is_some()),
+                ("timeout_seconds", llm.timeout_seconds.is_some()),
+                ("max_tokens", llm.max_tokens.is_some()),
+            ];
+            let replaced = replaces(llm.merge, default, base.llm.is_some());
+            self.record_section("llm", &fields, replaced, &source);
+            self.record_block("llm.network", llm.network.as_ref(), replaced, &source);
+            self.record_block("llm.resilience", llm.resilience.as_ref(), replaced, &source);
+            if replaced {
+                for field in LLM_DEFAULTED_FIELDS {
+                    let path = format!("llm.{}", field);
+                    self.sources.entry(path).or_insert(ValueSource::Default);
+                }
+            }
+        }
+        if let Some(templates) = &profile.templates {
+            let fields = [
+                ("cr", templates.cr.is_some()),
+                ("ppt", templates.ppt.is_some()),
+                ("anomaly", templates.anomaly.is_some()),
+            ];
+            let replaced = replaces(templates.merge, default, base.templates.is_some());
+            self.record_section("templates", &fields, replaced, &source);
+        }
+        if let Some(checklist) = &profile.checklist {
+            let thresholds = checklist.thresholds.unwrap_or_default();
+            let fields = [
+                ("thresholds.go", thresholds.go.is_some()),
+                ("thresholds.clarify", thresholds.clarify.is_some()),
+            ];
+            let replaced = replaces(checklist.merge, default, base.checklist.is_some());
+            self.record_section("checklist", &fields, replaced, &source);
+            self.record_block(
+                "checklist.criteria",
+                checklist.criteria.as_ref(),
+                replaced,
+                &source,
+            );
+            if replaced {
+                for field in CHECKLIST_DEFAULTED_FIELDS {
+                    let path = format!("checklist.{}", field);
+                    self.sources.entry(path).or_insert(ValueSource::Default);
+                }
+            }
+        }
+    }
+
+    /// Record the fields a profile sets in `section`, dropping the base fields
+    /// first when the section is replaced.
+    fn record_section(
+        &mut self,
+        section: &str,
+        fields: &[(&str, bool)],
+        replaced: bool,
+        source: &ValueSource,
+    ) {
+        for &(field, is_set) in fields {
+            let path = format!("{}.{}", section, field);
+            if is_set {
+                self.set(path, source.clone());
+            } else if replaced {
+                self.remove(&path);
+            }
+        }
+    }
+
+    /// Record the leaves of a block a profile sets as a whole (e.g.
+    /// `jira.resilience`), dropping the leaves of the block it replaces. When the
+    /// profile does not set the block, its leaves are dropped only if the
+    /// section is replaced.
+    fn record_block<T: Serialize>(
+        &mut self,
+        path: &str,
+        block: Option<&T>,
+        replaced: bool,
+        source: &ValueSource,
+    ) {
+        if block.is_none() && !replaced {
+            return;
+        }
+        let nested = format!("{}.", path);
+        self.sources
+            .retain(|field, _| field != path && !field.starts_with(&nested));
+        if let Some(block) = block {
+            let value =
+                serde_yaml::to_value(block).expect("configuration values serialize to YAML");
+            let mut leaves = Vec::new();
+            push_leaves(path, &value, &mut leaves);
+            for field in leaves {
+                self.set(field, source.clone());
+            }
+        }
+    }
+}
+
+/// Whether a profile section starts from scratch rather than from the base section.
+fn replaces(merge: Option<MergeStrategy>, default: MergeStrategy, has_base: bool) -> bool {
+    merge.unwrap_or(default) == MergeStrategy::Replace || !has_base
+}
+
+/// Dotted paths of every leaf that holds a value, read from the serialized
+/// configuration so that new fields are covered without being listed here.
+/// Profiles are not part of the effective configuration and are skipped.
+pub(crate) fn set_leaves(config: &ProjectConfig) -> Vec<String> {
+    let document = serde_yaml::to_value(config).expect("a configuration always serializes to YAML");
+    let mut leaves = Vec::new();
+    for (key, value) in document.as_mapping().into_iter().flatten() {
+        match key.as_str() {
+            Some("profiles") | None => {}
+            Some(key) => push_leaves(key, value, &mut leaves),
+        }
+    }
+    leaves
+}
+
+/// Append the leaf paths of `value` under `path`: mappings are walked by key,
+/// lists of mappings by index; scalars and lists of scalars are leaves.
+fn push_leaves(path: &str, value: &Value, leaves: &mut Vec<String>) {
+    match value {
+        Value::Null => {}
+        Value::Mapping(mapping) => {
+            for (key, child) in mapping {
+                if let Some(key) = key.as_str() {
+                    push_leaves(&format!("{}.{}", path, key), child, leaves);
+                }
+            }
+        }
+        Value::Sequence(items) if items.iter().any(Value::is_mapping) => {
+            for (index, item) in items.iter().enumerate() {
+                push_leaves(&format!("{}.{}", path, index), item, leaves);
+            }
+        }
+        _ => leaves.push(path.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file_distinguishes_file_and_default_values() {
+        let config: ProjectConfig = serde_yaml::from_str(
+            "project_name: \"demo\"\noutput_folder: \"./output\"\nllm:\n  mode: \"local\"\n  local_endpoint: \"http://localhost:11434\"\n",
+        )
+        .unwrap();
+        let in_file = [
+            "project_name",
+            "output_folder",
+            "llm.mode",
+            "llm.local_endpoint",
+        ];
+        let provenance = Provenance::from_file(&config, |f| in_file.contains(&f));
+
+        assert_eq!(provenance.get("llm.mode"), Some(&ValueSource::File));
+        assert_eq!(
+            provenance.get("llm.timeout_seconds"),
+            Some(&ValueSource::Default)
+        );
+        assert_eq!(provenance.get("llm.api_key"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ValueSource::Profile("dev".into()).to_string(),
+            "profile 'dev'"
+        );
+        assert_eq!(ValueSource::Profile(String::new()).to_string(), "profile");
+        assert_eq!(
+            ValueSource::Env("TF__JIRA__ENDPOINT".into()).to_string(),
+            "env TF__JIRA__ENDPOINT"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Retry and degraded-mode policy of the integrations.
+//!
+//! `jira`, `squash` and `llm` each accept a `resilience` block. Failed calls
+//! are retried with exponential backoff; once the attempts are exhausted, the
+//! `fallback` mode decides what happens:
+//!
+//! ```yaml
+//! jira:
+//!   endpoint: "https://jira.example.com"
+//!   resilience:
+//!     max_attempts: 5
+//!     base_delay_ms: 500
+//!     max_delay_ms: 30000
+//!     jitter: true
+//!     retry_on: [429, 502, 503, 504]
+//!     fallback: csv      # fail | csv | cache
+//! ```
+//!
+//! Every field has a default, so an empty block (or no block at all, see
+//! [`ProjectConfig::resilience_for`]) gives 3 attempts, 500 ms doubling up to
+//! 30 s with jitter, retries on 429/500/502/503/504, and no fallback.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ProjectConfig;
+use crate::error::ConfigError;
+use crate::network::Integration;
+
+/// Retry and fallback settings of one integration.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ResilienceConfig {
+    /// Total number of attempts, the first call included (default: 3)
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Delay before the first retry in milliseconds, doubled at each retry (default: 500)
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+
+    /// Upper bound of the delay between attempts in milliseconds (default: 30000)
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+
+    /// Whether delays are randomized to spread retries of concurrent clients (default: true)
+    #[serde(default = "default_jitter")]
+    pub jitter: bool,
+
+    /// HTTP statuses that are retried (default: 429, 500, 502, 503, 504)
+    #[serde(default = "default_retry_on")]
+    pub retry_on: Vec<u16>,
+
+    /// What to do once every attempt failed (default: fail)
+    #[serde(default)]
+    pub fallback: FallbackMode,
+}
+
+/// Degraded mode used when an integration stays unavailable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FallbackMode {
+    /// Report the error and stop
+    #[default]
+    Fail,
+    /// Switch to the CSV import/export workflow (Jira and Squash only)
+    Csv,
+    /// Use the last cached response
+    Cache,
+}
+
+impl fmt::Display for FallbackMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FallbackMode::Fail => write!(f, "fail"),
+            FallbackMode::Csv => write!(f, "csv"),
+            FallbackMode::Cache => write!(f, "cache"),
+        }
+    }
+}
+
+pub(crate) fn default_max_attempts() -> u32 {
+    3
+}
+
+pub(crate) fn default_base_delay_ms() -> u64 {
+    500
+}
+
+pub(crate) fn default_max_delay_ms() -> u64 {
+    30_000
+}
+
+pub(crate) fn default_jitter() -> bool {
+    true
+}
+
+pub(crate) fn default_retry_on() -> Vec<u16> {
+    vec![429, 500, 502, 503, 504]
+}
+
+impl Default for ResilienceConfig {
+    fn default() -> Self {
+        ResilienceConfig {
+            max_attempts: default_max_attempts(),
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            jitter: default_jitter(),
+            retry_on: default_retry_on(),
+            fallback: FallbackMode::default(),
+        }
+    }
+}
+
+impl ResilienceConfig {
+    /// Delay before retry number `retry` (1 for the first retry), before
+    /// jitter: `base_delay_ms * 2^(retry - 1)`, capped at `max_delay_ms`.
+    pub fn delay_before_retry(&self, retry: u32) -> Duration {
+        let factor = 2u64.saturating_pow(retry.saturating_sub(1));
+        let delay = self.base_delay_ms.saturating_mul(factor);
+        Duration::from_millis(delay.min(self.max_delay_ms))
+    }
+
+    /// Whether a response with this HTTP status is retried.
+    pub fn is_retryable(&self, status: u16) -> bool {
+        self.retry_on.contains(&status)
+    }
+}
+
+impl ProjectConfig {
+    /// Resilience policy of `integration`: its `resilience` block, or the
+    /// defaults when it has none (or the integration is not configured).
+    pub fn resilience_for(&self, integration: Integration) -> ResilienceConfig {
+        let configured = match integration {
+            Integration::Jira => self.jira.as_ref().and_then(|j| j.resilience.as_ref()),
+            Integration::Squash => self.squash.as_ref().and_then(|s| s.resilience.as_ref()),
+            Integration::Llm => self.llm.as_ref().and_then(|l| l.resilience.as_ref()),
+        };
+        configured.cloned().unwrap_or_default()
+    }
+}
+
+/// Validate the `resilience` block of `integration`, pushing at most one
+/// error per field.
+pub(crate) fn validate_resilience(
+    integration: Integration,
+    resilience: &ResilienceConfig,
+    errors: &mut Vec<ConfigError>,
+) {
+    let field = |name: &str| format!("{}.resilience.{}", integration, name);
+
+    if resilience.max_attempts == 0 {
+        errors.push(ConfigError::invalid_value(
+            field("max_attempts"),
+            "must be a positive integer (greater than 0)",
+            "the total number of attempts, 1 to disable retries (e.g., 3)",
+        ));
+    }
+    if resilience.max_attempts > 1 && resilience.base_delay_ms == 0 {
+        errors.push(ConfigError::invalid_value(
+            field("base_delay_ms"),
+            "must be a positive integer (greater than 0) when retries are enabled",
+            "a delay in milliseconds (e.g., 500)",
+        ));
+    }
+    if resilience.max_delay_ms < resilience.base_delay_ms {
+        errors.push(ConfigError::invalid_value(
+            field("max_delay_ms"),
+            format!(
+                "must not be below base_delay_ms ({})",
+                resilience.base_delay_ms
+            ),
+            format!(
+                "a delay of at least {} milliseconds (e.g., 30000)",
+                resilience.base_delay_ms
+            ),
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for (index, &status) in resilience.retry_on.iter().enumerate() {
+        let reason = if !(400..=599).contains(&status) {
+            "must be an HTTP error status (400-599)".to_string()
+        } else if !seen.insert(status) {
+            format!("lists status {} more than once", status)
+        } else {
+            continue;
+        };
+        errors.push(ConfigError::invalid_value(
+            field(&format!("retry_on.{}", index)),
+            reason,
+            "HTTP statuses like 429, 502, 503 or 504",
+        ));
+    }
+
+    if resilience.fallback == FallbackMode::Csv && integration == Integration::Llm {
+        errors.push(ConfigError::invalid_value(
+            field("fallback"),
+            "cannot be 'csv' for the LLM (CSV fallback is for Jira and Squash)",
+            "'fail' or 'cache'",
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resilience(yaml: &str) -> ResilienceConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_defaults_and_backoff() {
+        let defaults = resilience("{}");
+        assert_eq!(defaults, ResilienceConfig::default());
+        assert!(defaults.is_retryable(503));
+        assert!(!defaults.is_retryable(404));
+
+        let policy = resilience("base_delay_ms: 200\nmax_delay_ms: 1000\n");
+        let delays: Vec<u128> = (1..=5)
+            .map(|retry| policy.delay_before_retry(retry).as_millis())
+            .collect();
+        assert_eq!(delays, [200, 400, 800, 1000, 1000]);
+        assert_eq!(
+            policy.delay_before_retry(u32::MAX),
+            Duration::from_millis(1000)
+        );
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let invalid = resilience(
+            "max_attempts: 4\nbase_delay_ms: 0\nmax_delay_ms: 0\nretry_on: [503, 200, 503]\nfallback: csv\n",
+        );
+        let mut errors = Vec::new();
+        validate_resilience(Integration::Llm, &invalid, &mut errors);
+        let fields: Vec<&str> = errors.iter().filter_map(|e| e.field()).collect();
+        assert_eq!(
+            fields,
+            [
+                "llm.resilience.base_delay_ms",
+                "llm.resilience.retry_on.1",
+                "llm.resilience.retry_on.2",
+                "llm.resilience.fallback",
+            ]
+        );
+
+        let mut errors = Vec::new();
+        validate_resilience(Integration::Jira, &invalid, &mut errors);
+        assert!(errors
+            .iter()
+            .all(|e| e.field() != Some("jira.resilience.fallback")));
+    }
+}
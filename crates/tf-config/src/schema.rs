@@ -0,0 +1,129 @@
+//! JSON Schema for `config.yaml`.
+//!
+//! The schema is generated from the serde structs themselves ([`ProjectConfig`]
+//! and everything it contains), so it always matches what [`load_config`]
+//! accepts: field names, `auto`/`local`/`cloud` for `llm.mode`, defaults such
+//! as `timeout_seconds: 120`, and descriptions taken from the doc comments.
+//!
+//! The schema covers the shape of the file only. Checks that need more than a
+//! type (URL format, path traversal, cloud mode prerequisites) are still done by
+//! [`load_config`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! let schema = tf_config::config_schema_json();
+//! std::fs::write("tf-config.schema.json", schema).unwrap();
+//! ```
+//!
+//! Editors using the YAML language server (e.g. the VS Code YAML extension)
+//! pick the schema up from a modeline at the top of `config.yaml`:
+//!
+//! ```yaml
+//! # yaml-language-server: $schema=./tf-config.schema.json
+//! project_name: "my-project"
+//! ```
+//!
+//! [`load_config`]: crate::load_config
+
+use schemars::gen::SchemaSettings;
+
+use crate::config::ProjectConfig;
+
+/// Title of the generated schema.
+pub const SCHEMA_TITLE: &str = "tf config.yaml";
+
+/// JSON Schema (draft-07) describing `config.yaml`.
+///
+/// `ProfileOverride`, `JiraConfig`, `SquashConfig`, `LlmConfig`,
+/// `TemplatesConfig` and the enums are emitted under `definitions`.
+pub fn config_schema() -> serde_json::Value {
+    let generator = SchemaSettings::draft07().into_generator();
+    let mut schema = generator.into_root_schema_for::<ProjectConfig>();
+    schema.schema.metadata().title = Some(SCHEMA_TITLE.to_string());
+    serde_json::to_value(schema).expect("a JSON Schema always serializes to JSON")
+}
+
+/// Accepted values of an enum schema (`enum`, or `oneOf` single-value variants).
+pub(crate) fn enum_values(schema: &serde_json::Value) -> Vec<&str> {
+    let single = schema["enum"].as_array().into_iter().flatten();
+    let variants = schema["oneOf"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|variant| variant["enum"].as_array().into_iter().flatten());
+    single
+        .chain(variants)
+        .filter_map(serde_json::Value::as_str)
+        .collect()
+}
+
+/// Name of the enum definition (e.g. `LlmMode`) whose values are exactly
+/// `values`, in order.
+pub(crate) fn enum_definition(values: &[&str]) -> Option<String> {
+    let schema = config_schema();
+    let definitions = schema["definitions"].as_object()?;
+    definitions
+        .iter()
+        .find(|(_, definition)| enum_values(definition) == values)
+        .map(|(name, _)| name.clone())
+}
+
+/// [`config_schema`] as pretty-printed JSON, ready to be written to a file.
+pub fn config_schema_json() -> String {
+    serde_json::to_string_pretty(&config_schema())
+        .expect("a JSON value always serializes to a string")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_lists_llm_modes_and_defaults() {
+        let schema = config_schema();
+        let definitions = &schema["definitions"];
+
+        let modes: Vec<&serde_json::Value> = definitions["LlmMode"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| &variant["enum"][0])
+            .collect();
+        assert_eq!(modes, [&json!("auto"), &json!("local"), &json!("cloud")]);
+        let llm = &definitions["LlmConfig"]["properties"];
+        assert_eq!(llm["timeout_seconds"]["default"], json!(120));
+        assert_eq!(llm["max_tokens"]["default"], json!(4096));
+        assert_eq!(
+            llm["timeout_seconds"]["description"],
+            json!("Request timeout in seconds (default: 120)")
+        );
+    }
+
+    #[test]
+    fn test_schema_matches_struct_shape() {
+        let schema = config_schema();
+
+        assert_eq!(schema["title"], json!(SCHEMA_TITLE));
+        assert_eq!(schema["required"], json!(["output_folder", "project_name"]));
+        assert_eq!(schema["additionalProperties"], json!(false));
+        // Runtime-only fields are not part of the file format
+        for field in ["active_profile", "source", "base_dir", "provenance"] {
+            assert!(schema["properties"].get(field).is_none(), "{}", field);
+        }
+        for definition in [
+            "ProfileOverride",
+            "JiraConfig",
+            "SquashConfig",
+            "TemplatesConfig",
+            "MergeStrategy",
+        ] {
+            assert!(
+                schema["definitions"].get(definition).is_some(),
+                "{}",
+                definition
+            );
+        }
+    }
+}
@@ -0,0 +1,393 @@
+//! Named Jira import scopes and custom field mappings.
+//!
+//! `jira.scopes` defines the perimeters tickets are imported from, selected by
+//! name. A scope combines project keys, components, fix versions, a relative
+//! date window on the `updated` field and a free JQL fragment; all the filters
+//! it sets must match. `jira.fields` maps the logical fields the tool reads to
+//! the Jira field ids of the instance:
+//!
+//! ```yaml
+//! jira:
+//!   endpoint: "https://jira.example.com"
+//!   scopes:
+//!     sprint:
+//!       projects: [PAY, CHK]
+//!       components: ["Checkout"]
+//!       window: last_14d        # last_<N>h, last_<N>d or last_<N>w
+//!     release:
+//!       projects: [PAY]
+//!       fix_versions: ["2.4.0"]
+//!       jql: "status != Cancelled"
+//!   fields:
+//!     acceptance_criteria: customfield_10042
+//!     severity: customfield_10051
+//! ```
+//!
+//! A scope must set `projects` or `jql`, so it never selects the whole
+//! instance. Profiles replace `scopes` and `fields` as a whole.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::load_config;
+//!
+//! let config = load_config(Path::new("config.yaml"))?;
+//! if let Some(jira) = &config.jira {
+//!     let scope = jira.scope("sprint")?;
+//!     // project in (PAY, CHK) AND component in ("Checkout") AND updated >= -14d
+//!     println!("{}", scope.to_jql());
+//!     let _criteria = jira.field_id("acceptance_criteria");
+//! }
+//! # Ok::<(), tf_config::ConfigError>(())
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{is_valid_identifier, JiraConfig};
+use crate::error::ConfigError;
+
+/// One named import perimeter.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JiraScope {
+    /// Project keys (e.g. `PAY`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<String>,
+
+    /// JQL fragment combined with the other filters (without `ORDER BY`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jql: Option<String>,
+
+    /// Component names
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+
+    /// Fix version names
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fix_versions: Vec<String>,
+
+    /// Relative window on the `updated` date (e.g. `last_7d`, `last_2w`, `last_12h`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<String>,
+}
+
+/// Relative date window parsed from a `last_<N><unit>` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeWindow {
+    /// Number of units, greater than 0
+    pub amount: u32,
+    /// Unit of `amount`
+    pub unit: WindowUnit,
+}
+
+/// Unit of a [`RelativeWindow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowUnit {
+    /// `h`
+    Hours,
+    /// `d`
+    Days,
+    /// `w`
+    Weeks,
+}
+
+impl WindowUnit {
+    fn suffix(self) -> char {
+        match self {
+            WindowUnit::Hours => 'h',
+            WindowUnit::Days => 'd',
+            WindowUnit::Weeks => 'w',
+        }
+    }
+
+    fn seconds(self) -> u64 {
+        match self {
+            WindowUnit::Hours => 3_600,
+            WindowUnit::Days => 86_400,
+            WindowUnit::Weeks => 604_800,
+        }
+    }
+}
+
+impl RelativeWindow {
+    /// Parse `last_<N><unit>` with `N > 0` and a unit among `h`, `d` and `w`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let rest = value.strip_prefix("last_")?;
+        let unit = match rest.chars().last()? {
+            'h' => WindowUnit::Hours,
+            'd' => WindowUnit::Days,
+            'w' => WindowUnit::Weeks,
+            _ => return None,
+        };
+        let digits = &rest[..rest.len() - 1];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let amount: u32 = digits.parse().ok().filter(|&n| n > 0)?;
+        Some(RelativeWindow { amount, unit })
+    }
+
+    /// Length of the window.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(u64::from(self.amount).saturating_mul(self.unit.seconds()))
+    }
+
+    /// JQL relative date for the start of the window (e.g. `-7d`).
+    pub fn to_jql(&self) -> String {
+        format!("-{}{}", self.amount, self.unit.suffix())
+    }
+}
+
+impl fmt::Display for RelativeWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "last_{}{}", self.amount, self.unit.suffix())
+    }
+}
+
+impl JiraScope {
+    /// Parsed `window`, or `None` when unset (or invalid, which validation rejects).
+    pub fn window(&self) -> Option<RelativeWindow> {
+        self.window.as_deref().and_then(RelativeWindow::parse)
+    }
+
+    /// JQL query selecting the tickets of the scope, its filters joined by `AND`.
+    pub fn to_jql(&self) -> String {
+        let mut clauses = Vec::new();
+        if !self.projects.is_empty() {
+            clauses.push(format!("project in ({})", self.projects.join(", ")));
+        }
+        if !self.components.is_empty() {
+            clauses.push(format!("component in ({})", quoted_list(&self.components)));
+        }
+        if !self.fix_versions.is_empty() {
+            clauses.push(format!(
+                "fixVersion in ({})",
+                quoted_list(&self.fix_versions)
+            ));
+        }
+        if let Some(window) = self.window() {
+            clauses.push(format!("updated >= {}", window.to_jql()));
+        }
+        if let Some(jql) = &self.jql {
+            clauses.push(if clauses.is_empty() {
+                jql.clone()
+            } else {
+                format!("({})", jql)
+            });
+        }
+        clauses.join(" AND ")
+    }
+}
+
+impl JiraConfig {
+    /// Scope named `name` in `jira.scopes`.
+    ///
+    /// # Errors
+    ///
+    /// [`ConfigError::ScopeNotFound`] with the names of the defined scopes.
+    pub fn scope(&self, name: &str) -> Result<&JiraScope, ConfigError> {
+        let scopes = self.scopes.as_ref();
+        scopes
+            .and_then(|scopes| scopes.get(name))
+            .ok_or_else(|| ConfigError::ScopeNotFound {
+                requested: name.to_string(),
+                available: scopes
+                    .map(|scopes| scopes.keys().cloned().collect())
+                    .unwrap_or_default(),
+            })
+    }
+
+    /// Jira field id mapped to the logical field `name` in `jira.fields`
+    /// (e.g. `customfield_10042` for `acceptance_criteria`).
+    pub fn field_id(&self, name: &str) -> Option<&str> {
+        self.fields.as_ref()?.get(name).map(String::as_str)
+    }
+}
+
+/// Validate `jira.scopes` and `jira.fields`, pushing at most one error per field.
+pub(crate) fn validate_jira_scopes(jira: &JiraConfig, errors: &mut Vec<ConfigError>) {
+    for (name, scope) in jira.scopes.iter().flatten() {
+        let field = |path: &str| format!("jira.scopes.{}.{}", name, path);
+        if !is_valid_identifier(name) {
+            errors.push(ConfigError::invalid_value(
+                format!("jira.scopes.{}", name),
+                "has an invalid scope name",
+                "alphanumeric characters, hyphens (-) and underscores (_) only (e.g., 'sprint')",
+            ));
+            continue;
+        }
+        if scope.projects.is_empty() && scope.jql.is_none() {
+            errors.push(ConfigError::missing_field(
+                field("projects"),
+                "project keys (e.g., [PAY]) or a jql fragment limiting the scope",
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        for (index, key) in scope.projects.iter().enumerate() {
+            let reason = if !is_valid_project_key(key) {
+                format!("'{}' is not a valid project key", key)
+            } else if !seen.insert(key.as_str()) {
+                format!("lists project {} more than once", key)
+            } else {
+                continue;
+            };
+            errors.push(ConfigError::invalid_value(
+                field(&format!("projects.{}", index)),
+                reason,
+                "an uppercase project key starting with a letter (e.g., 'PAY')",
+            ));
+        }
+
+        if let Some(jql) = &scope.jql {
+            if jql.trim().is_empty() {
+                errors.push(ConfigError::invalid_value(
+                    field("jql"),
+                    "cannot be empty",
+                    "a JQL condition like 'status != Cancelled', or remove the field",
+                ));
+            } else if jql.to_ascii_lowercase().contains("order by") {
+                errors.push(ConfigError::invalid_value(
+                    field("jql"),
+                    "cannot contain ORDER BY (the fragment is combined with the other filters)",
+                    "a JQL condition without ORDER BY",
+                ));
+            }
+        }
+
+        for (list, values) in [
+            ("components", &scope.components),
+            ("fix_versions", &scope.fix_versions),
+        ] {
+            if let Some(index) = values.iter().position(|v| v.trim().is_empty()) {
+                errors.push(ConfigError::invalid_value(
+                    field(&format!("{}.{}", list, index)),
+                    "cannot be empty",
+                    "a name as shown in Jira",
+                ));
+            }
+        }
+
+        if let Some(window) = &scope.window {
+            if RelativeWindow::parse(window).is_none() {
+                errors.push(ConfigError::invalid_value(
+                    field("window"),
+                    format!("'{}' is not a relative window", window),
+                    "last_<N> followed by h, d or w (e.g., 'last_7d', 'last_2w')",
+                ));
+            }
+        }
+    }
+
+    for (name, id) in jira.fields.iter().flatten() {
+        let field = format!("jira.fields.{}", name);
+        if !is_valid_identifier(name) {
+            errors.push(ConfigError::invalid_value(
+                field,
+                "has an invalid field name",
+                "alphanumeric characters, hyphens (-) and underscores (_) only (e.g., 'severity')",
+            ));
+        } else if !is_valid_field_id(id) {
+            errors.push(ConfigError::invalid_value(
+                field,
+                format!("'{}' is not a Jira field id", id),
+                "a custom field id like 'customfield_10042' or a system field like 'priority'",
+            ));
+        }
+    }
+}
+
+/// Comma-separated list of JQL string literals.
+fn quoted_list(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|v| format!("\"{}\"", v.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Validate a Jira project key: an uppercase letter, then uppercase letters,
+/// digits or underscores
+fn is_valid_project_key(key: &str) -> bool {
+    key.starts_with(|c: char| c.is_ascii_uppercase())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Validate a Jira field id: `customfield_<digits>` or a system field name
+fn is_valid_field_id(id: &str) -> bool {
+    match id.strip_prefix("customfield_") {
+        Some(number) => !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()),
+        None => {
+            id.starts_with(|c: char| c.is_ascii_alphabetic())
+                && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jira(yaml: &str) -> JiraConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_scope_builds_jql_and_window() {
+        let jira = jira(
+            "endpoint: https://jira.example.com\nscopes:\n  sprint:\n    projects: [PAY, CHK]\n    components: [\"Check \\\"out\\\"\"]\n    window: last_2w\n    jql: \"status = Done OR status = Closed\"\n  raw:\n    jql: \"labels = qa\"\nfields:\n  severity: customfield_10051\n",
+        );
+        let sprint = jira.scope("sprint").unwrap();
+        assert_eq!(
+            sprint.to_jql(),
+            "project in (PAY, CHK) AND component in (\"Check \\\"out\\\"\") AND updated >= -2w AND (status = Done OR status = Closed)"
+        );
+        assert_eq!(
+            sprint.window().unwrap().duration(),
+            Duration::from_secs(14 * 86_400)
+        );
+        assert_eq!(jira.scope("raw").unwrap().to_jql(), "labels = qa");
+        assert_eq!(jira.field_id("severity"), Some("customfield_10051"));
+        assert_eq!(jira.field_id("acceptance_criteria"), None);
+
+        match jira.scope("release") {
+            Err(ConfigError::ScopeNotFound { available, .. }) => {
+                assert_eq!(available, ["raw", "sprint"]);
+            }
+            other => panic!("Expected ScopeNotFound, got: {:?}", other),
+        }
+        assert_eq!(RelativeWindow::parse("last_0d"), None);
+        assert_eq!(RelativeWindow::parse("last_7"), None);
+        assert_eq!(sprint.window().unwrap().to_string(), "last_2w");
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let invalid = jira(
+            "endpoint: https://jira.example.com\nscopes:\n  empty:\n    components: [\"\"]\n  bad:\n    projects: [PAY, pay, PAY]\n    jql: \"status = Done ORDER BY created\"\n    window: last_month\nfields:\n  severity: customfield_\n",
+        );
+        let mut errors = Vec::new();
+        validate_jira_scopes(&invalid, &mut errors);
+        let fields: Vec<&str> = errors.iter().filter_map(|e| e.field()).collect();
+        assert_eq!(
+            fields,
+            [
+                "jira.scopes.bad.projects.1",
+                "jira.scopes.bad.projects.2",
+                "jira.scopes.bad.jql",
+                "jira.scopes.bad.window",
+                "jira.scopes.empty.projects",
+                "jira.scopes.empty.components.0",
+                "jira.fields.severity",
+            ]
+        );
+    }
+}
@@ -31,7 +31,7 @@ use std::fs;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
-use crate::config::{redact_url_sensitive_params, TemplatesConfig};
+use crate::config::{redact_url_sensitive_params, ProjectConfig, TemplatesConfig};
 
 /// ZIP magic bytes: PK\x03\x04
 const ZIP_MAGIC: &[u8; 4] = b"PK\x03\x04";
@@ -251,6 +251,7 @@ impl fmt::Debug for LoadedTemplate {
 #[derive(Debug)]
 pub struct TemplateLoader<'a> {
     config: &'a TemplatesConfig,
+    base_dir: Option<&'a Path>,
 }
 
 impl<'a> TemplateLoader<'a> {
@@ -270,7 +271,54 @@ impl<'a> TemplateLoader<'a> {
     /// let loader = TemplateLoader::new(&config);
     /// ```
     pub fn new(config: &'a TemplatesConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            base_dir: None,
+        }
+    }
+
+    /// Resolve relative template paths against `base_dir` instead of the
+    /// current working directory.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tf_config::{TemplateLoader, TemplatesConfig};
+    ///
+    /// let config = TemplatesConfig {
+    ///     cr: Some("templates/cr.md".to_string()),
+    ///     ppt: None,
+    ///     anomaly: None,
+    /// };
+    /// // Loads /srv/project/templates/cr.md
+    /// let loader = TemplateLoader::new(&config).with_base_dir(Path::new("/srv/project"));
+    /// ```
+    pub fn with_base_dir(mut self, base_dir: &'a Path) -> Self {
+        self.base_dir = Some(base_dir);
+        self
+    }
+
+    /// Create a loader for the templates of a loaded project configuration.
+    ///
+    /// Relative template paths are resolved against the configuration file
+    /// directory ([`ProjectConfig::base_dir`]). Returns `None` when the
+    /// configuration has no `templates` section.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tf_config::{load_config, TemplateKind, TemplateLoader};
+    ///
+    /// let config = load_config(Path::new("project/config.yaml")).unwrap();
+    /// if let Some(loader) = TemplateLoader::from_project_config(&config) {
+    ///     let cr = loader.load_template(TemplateKind::Cr).unwrap();
+    ///     println!("Loaded {}", cr.path().display());
+    /// }
+    /// ```
+    pub fn from_project_config(config: &'a ProjectConfig) -> Option<Self> {
+        let loader = Self::new(config.templates.as_ref()?);
+        Some(match config.base_dir.as_deref() {
+            Some(base_dir) => loader.with_base_dir(base_dir),
+            None => loader,
+        })
     }
 
     /// Load a specific template by kind
@@ -278,8 +326,10 @@ impl<'a> TemplateLoader<'a> {
     /// Resolves the configured path, validates the file extension, reads the file,
     /// and validates the format before returning the loaded template.
     ///
-    /// **Note:** Relative paths are resolved against the current working directory,
-    /// not the config file location. Use absolute paths in config for portability.
+    /// **Note:** Relative paths are resolved against the base directory set with
+    /// [`with_base_dir`](Self::with_base_dir) (the config file directory when the
+    /// loader comes from [`from_project_config`](Self::from_project_config)), or
+    /// against the current working directory otherwise.
     ///
     /// ```no_run
     /// use tf_config::{TemplateLoader, TemplateKind, TemplatesConfig};
@@ -298,19 +348,21 @@ impl<'a> TemplateLoader<'a> {
         self.load_from_path(kind, path_str)
     }
 
-    /// Load a template from a resolved path string.
+    /// Load a template from a configured path string.
     ///
-    /// **Known limitation:** Relative paths are resolved against the current
-    /// working directory (`std::env::current_dir`), not against the config file
-    /// location. Callers running the CLI from a different directory may get
-    /// unexpected `FileNotFound` errors. Use absolute paths in config to avoid
-    /// ambiguity.
+    /// Relative paths are joined to the loader's base directory when one is set,
+    /// so errors report the path that was actually tried.
     fn load_from_path(
         &self,
         kind: TemplateKind,
         path_str: &str,
     ) -> Result<LoadedTemplate, TemplateError> {
-        let path = PathBuf::from(path_str);
+        let path = match self.base_dir {
+            Some(base_dir) if Path::new(path_str).is_relative() => base_dir.join(path_str),
+            _ => PathBuf::from(path_str),
+        };
+        let resolved = path.display().to_string();
+        let path_str = resolved.as_str();
         let path_for_error = sanitize_path_for_error(path_str);
 
         // Validate extension before reading (avoids unnecessary I/O)
@@ -1610,4 +1662,54 @@ mod tests {
         assert!(validate_extension(Path::new("test.pptx"), TemplateKind::Ppt).is_ok());
         assert!(validate_extension(Path::new("test.txt"), TemplateKind::Cr).is_err());
     }
+
+    #[test]
+    fn test_relative_paths_resolve_against_base_dir() {
+        let config = TemplatesConfig {
+            cr: Some("cr-test.md".to_string()),
+            ppt: None,
+            anomaly: None,
+        };
+        let base_dir = fixtures_path();
+        let loader = TemplateLoader::new(&config).with_base_dir(&base_dir);
+        let template = loader.load_template(TemplateKind::Cr).unwrap();
+        assert_eq!(template.path(), base_dir.join("cr-test.md"));
+
+        // Absolute paths ignore the base directory
+        let absolute = TemplatesConfig {
+            cr: Some(base_dir.join("cr-test.md").display().to_string()),
+            ppt: None,
+            anomaly: None,
+        };
+        let loader = TemplateLoader::new(&absolute).with_base_dir(Path::new("/nonexistent"));
+        assert!(loader.load_template(TemplateKind::Cr).is_ok());
+    }
+
+    #[test]
+    fn test_from_project_config_uses_config_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("templates")).unwrap();
+        fs::write(dir.path().join("templates/cr.md"), "# CR").unwrap();
+        let config_path = dir.path().join("config.yaml");
+        fs::write(
+            &config_path,
+            "project_name: \"demo\"\noutput_folder: \"./output\"\ntemplates:\n  cr: \"templates/cr.md\"\n",
+        )
+        .unwrap();
+
+        let config = crate::load_config(&config_path).unwrap();
+        let loader = TemplateLoader::from_project_config(&config).unwrap();
+        let template = loader.load_template(TemplateKind::Cr).unwrap();
+        assert_eq!(template.content_as_str().unwrap(), "# CR");
+
+        let missing = TemplateLoader::new(&TemplatesConfig {
+            cr: Some("templates/missing.md".to_string()),
+            ppt: None,
+            anomaly: None,
+        })
+        .with_base_dir(dir.path())
+        .load_template(TemplateKind::Cr)
+        .unwrap_err();
+        assert!(matches!(missing, TemplateError::FileNotFound { .. }));
+    }
 }
@@ -0,0 +1,449 @@
+//! Hot reload of the configuration and its templates.
+//!
+//! A [`ConfigWatcher`] keeps a validated configuration up to date during long
+//! interactive sessions. It watches:
+//!
+//! - the configuration file and the fragments it includes (see [`crate::include`])
+//! - the template files referenced by [`TemplatesConfig`](crate::TemplatesConfig)
+//!
+//! On change it runs [`load_config_with_profile`] (so `TF_PROFILE` and
+//! `default_profile` apply when no profile is given, and `TF__*` overrides
+//! are applied) and
+//! [`TemplateLoader::load_all`] again and publishes a [`ConfigEvent`] on its
+//! channel: the new configuration, or the error. After an error, the last good
+//! configuration stays active ([`ConfigWatcher::current`]) until the files are
+//! fixed; the files the failed load referenced are watched as well, so adding
+//! a fragment or template that was missing triggers a reload.
+//!
+//! Files are polled (modification time and size) from a background thread
+//! rather than watched with OS notifications, which behave differently per
+//! platform and are unreliable on network and container mounts. A change is
+//! picked up once it has been stable for one poll interval, so an editor
+//! saving in several steps causes a single reload.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use tf_config::{ConfigEvent, ConfigWatcher};
+//!
+//! let watcher = ConfigWatcher::start(Path::new("config.yaml"), Some("dev"))?;
+//! println!("Project: {}", watcher.current().config.project_name);
+//!
+//! for event in watcher.events() {
+//!     match event {
+//!         ConfigEvent::Reloaded(loaded) => println!("Reloaded {}", loaded.config.project_name),
+//!         ConfigEvent::Failed(err) => eprintln!("Keeping the previous configuration: {}", err),
+//!     }
+//! }
+//! # Ok::<(), tf_config::ReloadError>(())
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, RwLock};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+use serde_yaml::Value;
+
+use crate::config::{load_config_with_profile, ProjectConfig};
+use crate::error::ConfigError;
+use crate::format::ConfigFormat;
+use crate::include::{include_entries, INCLUDE_KEY};
+use crate::template::{LoadedTemplate, TemplateError, TemplateKind, TemplateLoader};
+
+/// Interval between two polls of the watched files.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A validated configuration together with its loaded templates.
+#[derive(Debug)]
+pub struct LoadedConfig {
+    /// The configuration, with the watched profile applied
+    pub config: ProjectConfig,
+    /// The configured templates, by kind
+    pub templates: HashMap<TemplateKind, LoadedTemplate>,
+}
+
+/// Why a reload was rejected.
+#[derive(Debug, thiserror::Error)]
+pub enum ReloadError {
+    /// The configuration did not load or validate
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    /// A template did not load or validate
+    #[error(transparent)]
+    Template(#[from] TemplateError),
+}
+
+/// Message published by a [`ConfigWatcher`] after a change.
+#[derive(Debug)]
+pub enum ConfigEvent {
+    /// The files changed and the new configuration is valid; it is now current
+    Reloaded(Arc<LoadedConfig>),
+    /// The files changed but the new configuration was rejected; the previous
+    /// one stays current
+    Failed(ReloadError),
+}
+
+/// Watches a configuration and republishes it when its files change.
+///
+/// The background thread stops when the watcher is dropped.
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    current: Arc<RwLock<Arc<LoadedConfig>>>,
+    events: Receiver<ConfigEvent>,
+    /// Dropping the sender wakes the thread up and stops it
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ConfigWatcher {
+    /// Load the configuration at `path`, with the profile selected as by
+    /// [`load_config_with_profile`], and start watching it, polling every
+    /// [`DEFAULT_POLL_INTERVAL`].
+    ///
+    /// # Errors
+    ///
+    /// The initial load must succeed: its error is returned and nothing is
+    /// watched.
+    pub fn start(path: &Path, profile: Option<&str>) -> Result<Self, ReloadError> {
+        Self::start_with_interval(path, profile, DEFAULT_POLL_INTERVAL)
+    }
+
+    /// [`ConfigWatcher::start`] with a custom poll interval.
+    pub fn start_with_interval(
+        path: &Path,
+        profile: Option<&str>,
+        poll_interval: Duration,
+    ) -> Result<Self, ReloadError> {
+        let target = WatchTarget {
+            path: path.to_path_buf(),
+            profile: profile.map(str::to_string),
+        };
+        let loaded = Arc::new(target.load()?);
+        // Stamp the files now, so that a change made right after `start`
+        // returns is not mistaken for the initial state
+        let watched = watched_paths(&loaded);
+        let stamp = fingerprint(&watched);
+        let current = Arc::new(RwLock::new(Arc::clone(&loaded)));
+        let (events_tx, events) = mpsc::channel();
+        let (stop, stop_rx) = mpsc::channel();
+
+        let shared = Arc::clone(&current);
+        let thread = std::thread::Builder::new()
+            .name("tf-config-watcher".to_string())
+            .spawn(move || {
+                target.run(watched, stamp, &shared, &events_tx, &stop_rx, poll_interval);
+            })
+            .map_err(ConfigError::IoError)?;
+
+        Ok(Self {
+            current,
+            events,
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+
+    /// The last configuration that loaded successfully.
+    pub fn current(&self) -> Arc<LoadedConfig> {
+        let current = self.current.read().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(&current)
+    }
+
+    /// Channel receiving one event per detected change.
+    pub fn events(&self) -> &Receiver<ConfigEvent> {
+        &self.events
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// What to reload: the configuration file and the profile to apply.
+struct WatchTarget {
+    path: PathBuf,
+    profile: Option<String>,
+}
+
+/// Modification time and size of each watched file (`None` when missing).
+type Fingerprint = Vec<(PathBuf, Option<(SystemTime, u64)>)>;
+
+impl WatchTarget {
+    fn load(&self) -> Result<LoadedConfig, ReloadError> {
+        let config = load_config_with_profile(&self.path, self.profile.as_deref())?;
+        let templates = match TemplateLoader::from_project_config(&config) {
+            Some(loader) => loader.load_all()?,
+            None => HashMap::new(),
+        };
+        Ok(LoadedConfig { config, templates })
+    }
+
+    /// Poll loop of the background thread.
+    fn run(
+        &self,
+        mut watched: Vec<PathBuf>,
+        mut loaded_stamp: Fingerprint,
+        current: &RwLock<Arc<LoadedConfig>>,
+        events: &Sender<ConfigEvent>,
+        stop: &Receiver<()>,
+        poll_interval: Duration,
+    ) {
+        // Changed fingerprint seen on the previous poll, waiting to settle
+        let mut pending: Option<Fingerprint> = None;
+
+        while let Err(RecvTimeoutError::Timeout) = stop.recv_timeout(poll_interval) {
+            let stamp = fingerprint(&watched);
+            if stamp == loaded_stamp {
+                pending = None;
+                continue;
+            }
+            if pending.as_ref() != Some(&stamp) {
+                pending = Some(stamp);
+                continue;
+            }
+            pending = None;
+
+            let event = match self.load() {
+                Ok(loaded) => {
+                    watched = watched_paths(&loaded);
+                    loaded_stamp = fingerprint(&watched);
+                    let loaded = Arc::new(loaded);
+                    *current.write().unwrap_or_else(|e| e.into_inner()) = Arc::clone(&loaded);
+                    ConfigEvent::Reloaded(loaded)
+                }
+                // Keep watching the files of the last good configuration, and add
+                // those the failed load read, so that creating a missing fragment or
+                // template triggers a reload
+                Err(err) => {
+                    for path in attempted_paths(&self.path) {
+                        if !watched.contains(&path) {
+                            watched.push(path);
+                        }
+                    }
+                    loaded_stamp = fingerprint(&watched);
+                    ConfigEvent::Failed(err)
+                }
+            };
+            if events.send(event).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Configuration file, fragments and template files of a loaded configuration.
+fn watched_paths(loaded: &LoadedConfig) -> Vec<PathBuf> {
+    let config = &loaded.config;
+    let mut paths: Vec<PathBuf> = match &config.source {
+        Some(source) => source.paths().map(Path::to_path_buf).collect(),
+        None => Vec::new(),
+    };
+    if let Some(templates) = &config.templates {
+        let configured = [&templates.cr, &templates.ppt, &templates.anomaly];
+        paths.extend(
+            configured
+                .into_iter()
+                .flatten()
+                .map(|p| config.resolve_path(p)),
+        );
+    }
+    paths
+}
+
+/// Files a load of `path` reads, from the raw documents: the configuration
+/// file, the fragments it includes (existing or not), and the template files of
+/// the `templates` sections. Used when the load fails, so nothing is validated.
+fn attempted_paths(path: &Path) -> Vec<PathBuf> {
+    let base_dir = parent_dir(path);
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(file) = pending.pop() {
+        if paths.contains(&file) {
+            continue;
+        }
+        paths.push(file.clone());
+        let Some(document) = std::fs::read_to_string(&file).ok().and_then(|content| {
+            ConfigFormat::from_path(&file)
+                .deserialize::<Value>(&content)
+                .ok()
+        }) else {
+            continue;
+        };
+        if let Some(Ok(entries)) = document.get(INCLUDE_KEY).map(include_entries) {
+            let dir = parent_dir(&file);
+            pending.extend(entries.iter().map(|entry| dir.join(entry)));
+        }
+        if let Some(templates) = document.get("templates").and_then(Value::as_mapping) {
+            let templates = templates.values().filter_map(Value::as_str);
+            paths.extend(templates.map(|template| base_dir.join(template)));
+        }
+    }
+    paths
+}
+
+/// Directory relative paths of `path` are resolved against.
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+fn fingerprint(paths: &[PathBuf]) -> Fingerprint {
+    paths
+        .iter()
+        .map(|path| {
+            let stamp = std::fs::metadata(path)
+                .ok()
+                .and_then(|meta| Some((meta.modified().ok()?, meta.len())));
+            (path.clone(), stamp)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const INTERVAL: Duration = Duration::from_millis(10);
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn write_config(dir: &Path, project_name: &str) {
+        fs::write(
+            dir.join("config.yaml"),
+            format!(
+                "include: [\"jira.yaml\"]\nproject_name: \"{}\"\noutput_folder: \"./output\"\ntemplates:\n  cr: \"./cr.md\"\nprofiles:\n  dev:\n    output_folder: \"./dev-output\"\n",
+                project_name
+            ),
+        )
+        .unwrap();
+    }
+
+    fn setup() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(dir.path(), "first");
+        fs::write(
+            dir.path().join("jira.yaml"),
+            "jira:\n  endpoint: \"https://jira.example.com\"\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("cr.md"), "# Daily report\n").unwrap();
+        dir
+    }
+
+    fn next_event(watcher: &ConfigWatcher) -> ConfigEvent {
+        watcher.events().recv_timeout(TIMEOUT).expect("an event")
+    }
+
+    #[test]
+    fn test_reloads_on_change_and_keeps_last_good_config() {
+        let dir = setup();
+        let path = dir.path().join("config.yaml");
+        let watcher = ConfigWatcher::start_with_interval(&path, Some("dev"), INTERVAL).unwrap();
+        assert_eq!(watcher.current().config.project_name, "first");
+        assert_eq!(watcher.current().config.output_folder, "./dev-output");
+
+        write_config(dir.path(), "second");
+        match next_event(&watcher) {
+            ConfigEvent::Reloaded(loaded) => {
+                assert_eq!(loaded.config.project_name, "second");
+                assert_eq!(loaded.config.active_profile.as_deref(), Some("dev"));
+            }
+            other => panic!("expected a reload, got {:?}", other),
+        }
+
+        fs::write(&path, "project_name: \"broken\"\n").unwrap();
+        match next_event(&watcher) {
+            ConfigEvent::Failed(ReloadError::Config(err)) => {
+                assert_eq!(err.field(), Some("output_folder"));
+            }
+            other => panic!("expected a failure, got {:?}", other),
+        }
+        assert_eq!(watcher.current().config.project_name, "second");
+
+        write_config(dir.path(), "fixed");
+        assert!(matches!(next_event(&watcher), ConfigEvent::Reloaded(_)));
+        assert_eq!(watcher.current().config.project_name, "fixed");
+    }
+
+    #[test]
+    fn test_watches_fragments_and_templates() {
+        let dir = setup();
+        let path = dir.path().join("config.yaml");
+        let watcher = ConfigWatcher::start_with_interval(&path, None, INTERVAL).unwrap();
+        assert!(watcher.current().templates.contains_key(&TemplateKind::Cr));
+
+        fs::write(
+            dir.path().join("jira.yaml"),
+            "jira:\n  endpoint: \"https://jira2.example.com\"\n",
+        )
+        .unwrap();
+        match next_event(&watcher) {
+            ConfigEvent::Reloaded(loaded) => assert_eq!(
+                loaded.config.jira.as_ref().unwrap().endpoint,
+                "https://jira2.example.com"
+            ),
+            other => panic!("expected a reload, got {:?}", other),
+        }
+
+        fs::write(dir.path().join("cr.md"), "").unwrap();
+        assert!(matches!(
+            next_event(&watcher),
+            ConfigEvent::Failed(ReloadError::Template(_))
+        ));
+        assert!(watcher.current().templates.contains_key(&TemplateKind::Cr));
+    }
+
+    #[test]
+    fn test_missing_fragment_is_watched_after_a_failed_reload() {
+        let dir = setup();
+        let path = dir.path().join("config.yaml");
+        let watcher = ConfigWatcher::start_with_interval(&path, None, INTERVAL).unwrap();
+
+        fs::write(
+            &path,
+            "include: [\"jira.yaml\", \"new.yaml\"]\nproject_name: \"first\"\noutput_folder: \"./output\"\n",
+        )
+        .unwrap();
+        match next_event(&watcher) {
+            ConfigEvent::Failed(ReloadError::Config(err)) => {
+                assert_eq!(err.field(), Some("include.1"));
+            }
+            other => panic!("expected a failure, got {:?}", other),
+        }
+
+        fs::write(
+            dir.path().join("new.yaml"),
+            "output_folder: \"./new-output\"\n",
+        )
+        .unwrap();
+        match next_event(&watcher) {
+            ConfigEvent::Reloaded(loaded) => {
+                assert_eq!(loaded.config.output_folder, "./output");
+                assert_eq!(loaded.config.source.as_ref().unwrap().paths().count(), 3);
+            }
+            other => panic!("expected a reload, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_initial_load_error_is_returned() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = ConfigWatcher::start(&dir.path().join("missing.yaml"), None).unwrap_err();
+        assert!(matches!(
+            err,
+            ReloadError::Config(ConfigError::FileNotFound { .. })
+        ));
+    }
+}
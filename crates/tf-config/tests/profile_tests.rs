@@ -21,7 +21,7 @@
 //! - `test_with_profile_rejects_invalid_jira_url_after_merge` - [HIGH] Invalid URL validation
 
 use std::path::PathBuf;
-use tf_config::{load_config, ConfigError};
+use tf_config::{load_config, ConfigError, ValueSource};
 
 /// Returns the path to a test fixture file, using CARGO_MANIFEST_DIR for robustness.
 fn fixture_path(name: &str) -> PathBuf {
@@ -635,3 +635,277 @@ fn test_with_profile_accepts_valid_squash_url() {
         .expect("merged config should have squash");
     assert_eq!(squash.endpoint, "https://squash.staging.example.com");
 }
+
+// =============================================================================
+// Profile inheritance (`extends`)
+// =============================================================================
+
+/// A profile extending another inherits its overrides and adds its own
+#[test]
+fn test_extends_applies_parent_then_child() {
+    let config = load_config(&fixture_path("config_profile_extends.yaml")).unwrap();
+
+    let merged = config.with_profile("preprod").unwrap();
+    assert_eq!(merged.active_profile, Some("preprod".to_string()));
+    assert_eq!(merged.output_folder, "./preprod-output");
+    assert_eq!(
+        merged.jira.as_ref().unwrap().endpoint,
+        "https://jira.staging.example.com"
+    );
+    assert_eq!(
+        merged.squash.as_ref().unwrap().endpoint,
+        "https://squash.staging.example.com"
+    );
+
+    assert_eq!(
+        config.profile_chain("preprod").unwrap(),
+        vec!["staging".to_string(), "preprod".to_string()]
+    );
+}
+
+/// The summary shows the resolved chain
+#[test]
+fn test_summary_shows_resolved_chain() {
+    let config = load_config(&fixture_path("config_profile_extends.yaml")).unwrap();
+
+    let summary = config
+        .with_profile("preprod")
+        .unwrap()
+        .active_profile_summary();
+    assert!(
+        summary.starts_with("Active profile: preprod (base -> staging -> preprod)"),
+        "unexpected summary: {}",
+        summary
+    );
+
+    let summary = config
+        .with_profile("staging")
+        .unwrap()
+        .active_profile_summary();
+    assert!(summary.starts_with("Active profile: staging\n"));
+}
+
+/// Errors in an inherited chain are still validated and located in the right profile
+#[test]
+fn test_extends_chain_is_validated_after_merge() {
+    let config = load_config(&fixture_path("config_profile_extends.yaml")).unwrap();
+
+    let err = config.with_profile("preprod_eu").unwrap_err();
+    assert_eq!(err.field(), Some("squash.endpoint"));
+    assert_eq!(err.location().map(|l| l.line), Some(29));
+}
+
+/// A loop in `extends` is rejected with the cycle spelled out
+#[test]
+fn test_extends_cycle_is_rejected() {
+    let err = load_config(&fixture_path("config_profile_extends_cycle.yaml")).unwrap_err();
+
+    match &err {
+        ConfigError::ProfileCycle { chain } => {
+            assert_eq!(chain, &["preprod", "staging", "preprod"]);
+        }
+        other => panic!("Expected ProfileCycle, got: {:?}", other),
+    }
+    assert!(err.to_string().contains("preprod -> staging -> preprod"));
+}
+
+// =============================================================================
+// Merge strategies
+// =============================================================================
+
+/// `merge: deep` only overrides the leaf fields set in the profile
+#[test]
+fn test_deep_merge_overrides_only_specified_fields() {
+    let config = load_config(&fixture_path("config_profile_deep_merge.yaml")).unwrap();
+    let merged = config.with_profile("dev").unwrap();
+
+    let llm = merged.llm.as_ref().unwrap();
+    assert_eq!(llm.cloud_model.as_deref(), Some("gpt-4o"));
+    assert_eq!(
+        llm.cloud_endpoint.as_deref(),
+        Some("https://api.example.com/v1")
+    );
+    assert_eq!(llm.api_key.as_deref(), Some("sk-base-secret-key"));
+    assert_eq!(llm.timeout_seconds, 60);
+}
+
+/// A section can opt back into replace semantics inside a deep-merged profile
+#[test]
+fn test_section_merge_overrides_profile_merge() {
+    let config = load_config(&fixture_path("config_profile_deep_merge.yaml")).unwrap();
+    let merged = config.with_profile("dev").unwrap();
+
+    let templates = merged.templates.as_ref().unwrap();
+    assert_eq!(templates.ppt.as_deref(), Some("./templates/dev.pptx"));
+    assert!(templates.cr.is_none());
+    assert!(templates.anomaly.is_none());
+
+    let qa = config.with_profile("qa").unwrap();
+    let jira = qa.jira.as_ref().unwrap();
+    assert_eq!(jira.endpoint, "https://jira.qa.example.com");
+    assert_eq!(jira.token.as_deref(), Some("base-secret-token"));
+}
+
+/// Without `merge`, a section keeps replacing the base section as a whole
+#[test]
+fn test_replace_remains_the_default_merge_strategy() {
+    let config = load_config(&fixture_path("config_with_profiles.yaml")).unwrap();
+    let merged = config.with_profile("with_llm").unwrap();
+
+    let llm = merged.llm.as_ref().unwrap();
+    assert_eq!(llm.local_endpoint.as_deref(), Some("http://localhost:8080"));
+    assert_eq!(llm.timeout_seconds, 120);
+}
+
+/// An unknown merge strategy is reported on the `merge` key of the profile or
+/// section, with the accepted strategies
+#[test]
+fn test_unknown_merge_strategy_names_the_merge_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.yaml");
+    let base = "project_name: \"demo\"\noutput_folder: \"./output\"\nprofiles:\n  dev:\n";
+    for (profile, field, line) in [
+        ("    merge: local\n", "profiles.dev.merge", 5),
+        (
+            "    jira:\n      merge: local\n      endpoint: \"https://jira.dev.example.com\"\n",
+            "profiles.dev.jira.merge",
+            6,
+        ),
+    ] {
+        std::fs::write(&path, format!("{}{}", base, profile)).unwrap();
+        let err = load_config(&path).unwrap_err();
+        assert_eq!(err.field(), Some(field));
+        assert_eq!(err.location().map(|l| l.line), Some(line));
+        let message = err.to_string();
+        assert!(
+            message.contains("is not a valid merge strategy"),
+            "{}",
+            message
+        );
+        assert!(message.contains("'replace', 'deep'"), "{}", message);
+    }
+}
+
+/// Each effective value records whether it came from the file, a default,
+/// the profile, the environment or an explicit override
+#[test]
+fn test_provenance_tracks_each_layer() {
+    let config = load_config(&fixture_path("config_with_profiles.yaml")).unwrap();
+    let mut config = config.with_profile("dev").unwrap();
+    config
+        .apply_env_overrides_from(vec![(
+            "TF__LLM__TIMEOUT_SECONDS".to_string(),
+            "300".to_string(),
+        )])
+        .unwrap();
+    config.set_override("llm.mode", "local").unwrap();
+
+    let provenance = &config.provenance;
+    assert_eq!(provenance.get("project_name"), Some(&ValueSource::File));
+    assert_eq!(provenance.get("squash.endpoint"), Some(&ValueSource::File));
+    assert_eq!(
+        provenance.get("jira.endpoint"),
+        Some(&ValueSource::Profile("dev".into()))
+    );
+    assert_eq!(
+        provenance.get("llm.timeout_seconds"),
+        Some(&ValueSource::Env("TF__LLM__TIMEOUT_SECONDS".into()))
+    );
+    assert_eq!(provenance.get("llm.mode"), Some(&ValueSource::Override));
+    assert_eq!(
+        provenance.get("llm.max_tokens"),
+        Some(&ValueSource::Default)
+    );
+    assert_eq!(provenance.get("llm.api_key"), None);
+
+    let summary = config.active_profile_summary_with_sources();
+    assert!(summary.contains("(from profile 'dev')"));
+    assert!(summary.contains("(from override)"));
+    assert!(!config.active_profile_summary().contains("(from "));
+}
+
+/// `load_config_with_profile` applies the explicit profile over
+/// `default_profile`, and an unknown `default_profile` is rejected on load
+#[test]
+fn test_load_config_with_profile_and_default_profile() {
+    use tf_config::load_config_with_profile;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.yaml");
+    std::fs::write(
+        &path,
+        "project_name: \"demo\"\noutput_folder: \"./output\"\ndefault_profile: \"dev\"\nprofiles:\n  dev:\n    output_folder: \"./dev-output\"\n  ci:\n    output_folder: \"./ci-output\"\n",
+    )
+    .unwrap();
+
+    let config = load_config_with_profile(&path, Some("ci")).unwrap();
+    assert_eq!(config.active_profile.as_deref(), Some("ci"));
+    assert_eq!(config.output_folder, "./ci-output");
+    assert!(config
+        .active_profile_summary()
+        .contains("selected explicitly"));
+    // The file itself is unchanged by the selection
+    assert_eq!(
+        load_config(&path).unwrap().default_profile.as_deref(),
+        Some("dev")
+    );
+
+    std::fs::write(
+        &path,
+        "project_name: \"demo\"\noutput_folder: \"./output\"\ndefault_profile: \"qa\"\nprofiles:\n  dev: {}\n",
+    )
+    .unwrap();
+    let err = load_config_with_profile(&path, Some("dev")).unwrap_err();
+    assert_eq!(err.field(), Some("default_profile"));
+    assert_eq!(err.location().map(|l| l.line), Some(3));
+}
+
+/// Network, resilience, checklist and anonymization values are tracked per leaf
+/// and shown in the summary with their source
+#[test]
+fn test_provenance_covers_every_section() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.yaml");
+    std::fs::write(
+        &path,
+        "project_name: \"demo\"\noutput_folder: \"./output\"\nnetwork:\n  proxy: \"http://proxy.corp:3128\"\nchecklist:\n  criteria:\n    - id: ac\n      label: \"Acceptance criteria\"\n      weight: 100\njira:\n  endpoint: \"https://jira.example.com\"\nprofiles:\n  ci:\n    jira:\n      endpoint: \"https://jira.ci.example.com\"\n      resilience:\n        max_attempts: 5\n",
+    )
+    .unwrap();
+
+    let config = load_config(&path).unwrap().with_profile("ci").unwrap();
+    let provenance = &config.provenance;
+    assert_eq!(provenance.get("network.proxy"), Some(&ValueSource::File));
+    assert_eq!(
+        provenance.get("checklist.criteria.0.weight"),
+        Some(&ValueSource::File)
+    );
+    assert_eq!(
+        provenance.get("checklist.thresholds.go"),
+        Some(&ValueSource::Default)
+    );
+    assert_eq!(
+        provenance.get("jira.resilience.max_attempts"),
+        Some(&ValueSource::Profile("ci".into()))
+    );
+    assert_eq!(
+        provenance.get("jira.resilience.base_delay_ms"),
+        Some(&ValueSource::Profile("ci".into()))
+    );
+
+    let summary = config.active_profile_summary_with_sources();
+    assert!(
+        summary.contains("resilience (from profile 'ci')"),
+        "{}",
+        summary
+    );
+    assert!(
+        summary.contains("Network: proxy (from file)"),
+        "{}",
+        summary
+    );
+    assert!(
+        summary.contains("Checklist: criteria (from default, file), thresholds (from default)"),
+        "{}",
+        summary
+    );
+}
@@ -13,8 +13,10 @@
 //! - AC #1: Profile merge logic and summary display
 //! - AC #3: ProfileOverride redacts secrets in Debug output
 
-use tf_config::ProfileOverride;
 use tf_config::{JiraConfig, LlmConfig, LlmMode, ProjectConfig, SquashConfig, TemplatesConfig};
+use tf_config::{
+    LlmOverride, MergeStrategy, ProfileOverride, SquashOverride, CURRENT_CONFIG_VERSION,
+};
 
 // =============================================================================
 // Test 1: ProfileOverride with jira override redacts token in Debug
@@ -29,14 +31,24 @@ use tf_config::{JiraConfig, LlmConfig, LlmMode, ProjectConfig, SquashConfig, Tem
 #[test]
 fn test_profile_override_redacts_jira_token_in_debug() {
     let profile = ProfileOverride {
-        jira: Some(JiraConfig {
-            endpoint: "https://jira.dev.example.com".to_string(),
-            token: Some("super-secret-jira-token-12345".to_string()),
-        }),
+        extends: None,
+        merge: None,
+        jira: Some(
+            JiraConfig {
+                endpoint: "https://jira.dev.example.com".to_string(),
+                token: Some("super-secret-jira-token-12345".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            }
+            .into(),
+        ),
         squash: None,
         llm: None,
         templates: None,
         output_folder: None,
+        checklist: None,
     };
 
     let debug_str = format!("{:?}", profile);
@@ -61,21 +73,29 @@ fn test_profile_override_redacts_jira_token_in_debug() {
 #[test]
 fn test_profile_override_redacts_llm_api_key_in_debug() {
     let profile = ProfileOverride {
+        extends: None,
+        merge: None,
         jira: None,
         squash: None,
-        llm: Some(LlmConfig {
-            mode: LlmMode::Cloud,
-            local_endpoint: None,
-            local_model: None,
-            cloud_enabled: true,
-            cloud_endpoint: Some("https://api.openai.com/v1".to_string()),
-            cloud_model: Some("gpt-4".to_string()),
-            api_key: Some("sk-secret-openai-key-xyz789".to_string()),
-            timeout_seconds: 120,
-            max_tokens: 4096,
-        }),
+        llm: Some(
+            LlmConfig {
+                mode: LlmMode::Cloud,
+                local_endpoint: None,
+                local_model: None,
+                cloud_enabled: true,
+                cloud_endpoint: Some("https://api.openai.com/v1".to_string()),
+                cloud_model: Some("gpt-4".to_string()),
+                api_key: Some("sk-secret-openai-key-xyz789".to_string()),
+                timeout_seconds: 120,
+                max_tokens: 4096,
+                network: None,
+                resilience: None,
+            }
+            .into(),
+        ),
         templates: None,
         output_folder: None,
+        checklist: None,
     };
 
     let debug_str = format!("{:?}", profile);
@@ -100,15 +120,23 @@ fn test_profile_override_redacts_llm_api_key_in_debug() {
 #[test]
 fn test_profile_override_redacts_squash_password_in_debug() {
     let profile = ProfileOverride {
+        extends: None,
+        merge: None,
         jira: None,
-        squash: Some(SquashConfig {
-            endpoint: "https://squash.staging.example.com".to_string(),
-            username: Some("testuser".to_string()),
-            password: Some("squash-secret-password-abc".to_string()),
-        }),
+        squash: Some(
+            SquashConfig {
+                endpoint: "https://squash.staging.example.com".to_string(),
+                username: Some("testuser".to_string()),
+                password: Some("squash-secret-password-abc".to_string()),
+                network: None,
+                resilience: None,
+            }
+            .into(),
+        ),
         llm: None,
         templates: None,
         output_folder: None,
+        checklist: None,
     };
 
     let debug_str = format!("{:?}", profile);
@@ -137,28 +165,48 @@ fn test_profile_override_redacted_trait_method() {
     use tf_config::Redact;
 
     let profile = ProfileOverride {
-        jira: Some(JiraConfig {
-            endpoint: "https://jira.example.com".to_string(),
-            token: Some("super-secret-token-xyz".to_string()),
-        }),
-        squash: Some(SquashConfig {
-            endpoint: "https://squash.example.com".to_string(),
-            username: Some("user".to_string()),
-            password: Some("secret-password-abc".to_string()),
-        }),
-        llm: Some(LlmConfig {
-            mode: LlmMode::Cloud,
-            local_endpoint: None,
-            local_model: None,
-            cloud_enabled: true,
-            cloud_endpoint: Some("https://api.openai.com/v1".to_string()),
-            cloud_model: Some("gpt-4".to_string()),
-            api_key: Some("sk-api-key-12345".to_string()),
-            timeout_seconds: 120,
-            max_tokens: 4096,
-        }),
+        extends: None,
+        merge: None,
+        jira: Some(
+            JiraConfig {
+                endpoint: "https://jira.example.com".to_string(),
+                token: Some("super-secret-token-xyz".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            }
+            .into(),
+        ),
+        squash: Some(
+            SquashConfig {
+                endpoint: "https://squash.example.com".to_string(),
+                username: Some("user".to_string()),
+                password: Some("secret-password-abc".to_string()),
+                network: None,
+                resilience: None,
+            }
+            .into(),
+        ),
+        llm: Some(
+            LlmConfig {
+                mode: LlmMode::Cloud,
+                local_endpoint: None,
+                local_model: None,
+                cloud_enabled: true,
+                cloud_endpoint: Some("https://api.openai.com/v1".to_string()),
+                cloud_model: Some("gpt-4".to_string()),
+                api_key: Some("sk-api-key-12345".to_string()),
+                timeout_seconds: 120,
+                max_tokens: 4096,
+                network: None,
+                resilience: None,
+            }
+            .into(),
+        ),
         templates: None,
         output_folder: Some("./output".to_string()),
+        checklist: None,
     };
 
     // Call .redacted() directly
@@ -214,16 +262,24 @@ fn test_profile_override_redacted_trait_method() {
 fn test_empty_profile_preserves_base_config() {
     // Create a fully populated base config
     let base_config = ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
         project_name: "original-project".to_string(),
         output_folder: "./original-output".to_string(),
         jira: Some(JiraConfig {
             endpoint: "https://jira.original.com".to_string(),
             token: Some("original-token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         }),
         squash: Some(SquashConfig {
             endpoint: "https://squash.original.com".to_string(),
             username: Some("original-user".to_string()),
             password: Some("original-pass".to_string()),
+            network: None,
+            resilience: None,
         }),
         llm: Some(LlmConfig {
             mode: LlmMode::Local,
@@ -235,6 +291,8 @@ fn test_empty_profile_preserves_base_config() {
             api_key: None,
             timeout_seconds: 60,
             max_tokens: 2048,
+            network: None,
+            resilience: None,
         }),
         templates: Some(TemplatesConfig {
             cr: Some("./templates/cr.md".to_string()),
@@ -242,7 +300,14 @@ fn test_empty_profile_preserves_base_config() {
             anomaly: Some("./templates/anomaly.md".to_string()),
         }),
         profiles: None,
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
         active_profile: None,
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
     };
 
     // Create an empty profile override (all None)
@@ -288,16 +353,24 @@ fn test_empty_profile_preserves_base_config() {
 fn test_partial_override_only_changes_specified_fields() {
     // Create a base config with all integrations
     let base_config = ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
         project_name: "my-project".to_string(),
         output_folder: "./output/default".to_string(),
         jira: Some(JiraConfig {
             endpoint: "https://jira.prod.com".to_string(),
             token: Some("prod-token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         }),
         squash: Some(SquashConfig {
             endpoint: "https://squash.prod.com".to_string(),
             username: Some("prod-user".to_string()),
             password: Some("prod-pass".to_string()),
+            network: None,
+            resilience: None,
         }),
         llm: Some(LlmConfig {
             mode: LlmMode::Cloud,
@@ -309,6 +382,8 @@ fn test_partial_override_only_changes_specified_fields() {
             api_key: Some("sk-prod-key".to_string()),
             timeout_seconds: 120,
             max_tokens: 4096,
+            network: None,
+            resilience: None,
         }),
         templates: Some(TemplatesConfig {
             cr: Some("./templates/cr.md".to_string()),
@@ -316,16 +391,26 @@ fn test_partial_override_only_changes_specified_fields() {
             anomaly: None,
         }),
         profiles: None,
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
         active_profile: None,
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
     };
 
     // Create a profile that only overrides output_folder
     let partial_profile = ProfileOverride {
+        extends: None,
+        merge: None,
         jira: None,
         squash: None,
         llm: None,
         templates: None,
         output_folder: Some("./output/staging".to_string()),
+        checklist: None,
     };
 
     // Apply the partial profile
@@ -367,33 +452,58 @@ fn test_partial_override_only_changes_specified_fields() {
 #[test]
 fn test_partial_override_replaces_entire_section() {
     let base_config = ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
         project_name: "my-project".to_string(),
         output_folder: "./output".to_string(),
         jira: Some(JiraConfig {
             endpoint: "https://jira.prod.com".to_string(),
             token: Some("prod-token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         }),
         squash: Some(SquashConfig {
             endpoint: "https://squash.prod.com".to_string(),
             username: Some("prod-user".to_string()),
             password: Some("prod-pass".to_string()),
+            network: None,
+            resilience: None,
         }),
         llm: None,
         templates: None,
         profiles: None,
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
         active_profile: None,
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
     };
 
     // Create a profile that overrides jira with a different endpoint and token
     let jira_override_profile = ProfileOverride {
-        jira: Some(JiraConfig {
-            endpoint: "https://jira.staging.com".to_string(),
-            token: Some("staging-token".to_string()),
-        }),
+        extends: None,
+        merge: None,
+        jira: Some(
+            JiraConfig {
+                endpoint: "https://jira.staging.com".to_string(),
+                token: Some("staging-token".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            }
+            .into(),
+        ),
         squash: None,
         llm: None,
         templates: None,
         output_folder: None,
+        checklist: None,
     };
 
     let merged = base_config.apply_profile(&jira_override_profile);
@@ -449,14 +559,24 @@ fn test_profile_override_default_has_all_none() {
 #[test]
 fn test_profile_override_is_clone() {
     let profile = ProfileOverride {
-        jira: Some(JiraConfig {
-            endpoint: "https://jira.example.com".to_string(),
-            token: Some("my-token".to_string()),
-        }),
+        extends: None,
+        merge: None,
+        jira: Some(
+            JiraConfig {
+                endpoint: "https://jira.example.com".to_string(),
+                token: Some("my-token".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            }
+            .into(),
+        ),
         squash: None,
         llm: None,
         templates: None,
         output_folder: Some("./custom-output".to_string()),
+        checklist: None,
     };
 
     let cloned = profile.clone();
@@ -464,8 +584,8 @@ fn test_profile_override_is_clone() {
     // Verify the clone has the same values
     assert!(cloned.jira.is_some());
     assert_eq!(
-        cloned.jira.as_ref().unwrap().endpoint,
-        "https://jira.example.com"
+        cloned.jira.as_ref().unwrap().endpoint.as_deref(),
+        Some("https://jira.example.com")
     );
     assert!(cloned.squash.is_none());
     assert_eq!(cloned.output_folder.as_ref().unwrap(), "./custom-output");
@@ -483,27 +603,43 @@ fn test_profile_override_is_clone() {
 #[test]
 fn test_profile_with_none_preserves_base_value() {
     let base_config = ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
         project_name: "my-project".to_string(),
         output_folder: "./output".to_string(),
         jira: Some(JiraConfig {
             endpoint: "https://jira.prod.com".to_string(),
             token: Some("prod-token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         }),
         squash: None,
         llm: None,
         templates: None,
         profiles: None,
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
         active_profile: None,
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
     };
 
     // A profile with jira = None means "don't change jira" (preserve base)
     // This is the default behavior for partial overrides
     let preserve_profile = ProfileOverride {
+        extends: None,
+        merge: None,
         jira: None, // None means "keep the base value"
         squash: None,
         llm: None,
         templates: None,
         output_folder: None,
+        checklist: None,
     };
 
     let merged = base_config.apply_profile(&preserve_profile);
@@ -524,11 +660,17 @@ fn test_profile_with_none_preserves_base_value() {
 #[test]
 fn test_active_profile_summary_shows_profile_and_values() {
     let config = ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
         project_name: "test-project".to_string(),
         output_folder: "./output".to_string(),
         jira: Some(JiraConfig {
             endpoint: "https://jira.prod.example.com".to_string(),
             token: Some("secret-token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         }),
         squash: None,
         llm: Some(LlmConfig {
@@ -541,10 +683,19 @@ fn test_active_profile_summary_shows_profile_and_values() {
             api_key: None,
             timeout_seconds: 60,
             max_tokens: 2048,
+            network: None,
+            resilience: None,
         }),
         templates: None,
         profiles: None,
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
         active_profile: Some("dev".to_string()),
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
     };
 
     let summary = config.active_profile_summary();
@@ -582,6 +733,8 @@ fn test_active_profile_summary_shows_profile_and_values() {
 #[test]
 fn test_active_profile_summary_no_profile() {
     let config = ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
         project_name: "test-project".to_string(),
         output_folder: "./output".to_string(),
         jira: None,
@@ -589,7 +742,14 @@ fn test_active_profile_summary_no_profile() {
         llm: None,
         templates: None,
         profiles: None,
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
         active_profile: None,
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
     };
 
     let summary = config.active_profile_summary();
@@ -609,38 +769,64 @@ fn test_active_profile_summary_no_profile() {
 #[test]
 fn test_multiple_profile_overrides_chain() {
     let base_config = ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
         project_name: "my-project".to_string(),
         output_folder: "./output/base".to_string(),
         jira: Some(JiraConfig {
             endpoint: "https://jira.base.com".to_string(),
             token: Some("base-token".to_string()),
+            network: None,
+            resilience: None,
+            scopes: None,
+            fields: None,
         }),
         squash: None,
         llm: None,
         templates: None,
         profiles: None,
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
         active_profile: None,
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
     };
 
     // First profile: change output folder
     let profile1 = ProfileOverride {
+        extends: None,
+        merge: None,
         jira: None,
         squash: None,
         llm: None,
         templates: None,
         output_folder: Some("./output/staging".to_string()),
+        checklist: None,
     };
 
     // Second profile: change jira endpoint
     let profile2 = ProfileOverride {
-        jira: Some(JiraConfig {
-            endpoint: "https://jira.staging.com".to_string(),
-            token: Some("staging-token".to_string()),
-        }),
+        extends: None,
+        merge: None,
+        jira: Some(
+            JiraConfig {
+                endpoint: "https://jira.staging.com".to_string(),
+                token: Some("staging-token".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            }
+            .into(),
+        ),
         squash: None,
         llm: None,
         templates: None,
         output_folder: None,
+        checklist: None,
     };
 
     // Apply profiles in sequence
@@ -666,14 +852,24 @@ fn test_multiple_profile_overrides_chain() {
 #[test]
 fn test_profile_override_partial_eq() {
     let profile1 = ProfileOverride {
-        jira: Some(JiraConfig {
-            endpoint: "https://jira.example.com".to_string(),
-            token: Some("secret-token".to_string()),
-        }),
+        extends: None,
+        merge: None,
+        jira: Some(
+            JiraConfig {
+                endpoint: "https://jira.example.com".to_string(),
+                token: Some("secret-token".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            }
+            .into(),
+        ),
         squash: None,
         llm: None,
         templates: None,
         output_folder: Some("./output".to_string()),
+        checklist: None,
     };
 
     // Clone should be equal
@@ -682,14 +878,24 @@ fn test_profile_override_partial_eq() {
 
     // Different output_folder should not be equal
     let profile3 = ProfileOverride {
-        jira: Some(JiraConfig {
-            endpoint: "https://jira.example.com".to_string(),
-            token: Some("secret-token".to_string()),
-        }),
+        extends: None,
+        merge: None,
+        jira: Some(
+            JiraConfig {
+                endpoint: "https://jira.example.com".to_string(),
+                token: Some("secret-token".to_string()),
+                network: None,
+                resilience: None,
+                scopes: None,
+                fields: None,
+            }
+            .into(),
+        ),
         squash: None,
         llm: None,
         templates: None,
         output_folder: Some("./different-output".to_string()),
+        checklist: None,
     };
     assert_ne!(
         profile1, profile3,
@@ -701,3 +907,192 @@ fn test_profile_override_partial_eq() {
     let default2 = ProfileOverride::default();
     assert_eq!(default1, default2, "Default profiles should be equal");
 }
+
+// =============================================================================
+// Profile inheritance (`extends`)
+// =============================================================================
+
+/// Builds a base configuration with the given profiles.
+fn config_with_profiles(profiles: Vec<(&str, ProfileOverride)>) -> ProjectConfig {
+    ProjectConfig {
+        config_version: CURRENT_CONFIG_VERSION,
+        include: None,
+        project_name: "my-project".to_string(),
+        output_folder: "./output".to_string(),
+        jira: None,
+        squash: None,
+        llm: None,
+        templates: None,
+        profiles: Some(
+            profiles
+                .into_iter()
+                .map(|(name, p)| (name.to_string(), p))
+                .collect(),
+        ),
+        checklist: None,
+        default_profile: None,
+        anonymization: None,
+        active_profile: None,
+        source: None,
+        base_dir: None,
+        provenance: Default::default(),
+        network: None,
+    }
+}
+
+/// A profile extending itself is a cycle of length one.
+#[test]
+fn test_self_extending_profile_is_a_cycle() {
+    let config = config_with_profiles(vec![(
+        "dev",
+        ProfileOverride {
+            extends: Some("dev".to_string()),
+            ..Default::default()
+        },
+    )]);
+
+    let err = config.with_profile("dev").unwrap_err();
+    assert!(
+        matches!(&err, tf_config::ConfigError::ProfileCycle { chain } if chain == &["dev", "dev"]),
+        "expected ProfileCycle, got: {:?}",
+        err
+    );
+}
+
+/// Extending an undefined profile names the offending `extends` key.
+#[test]
+fn test_extends_unknown_profile_is_rejected() {
+    let config = config_with_profiles(vec![
+        (
+            "preprod",
+            ProfileOverride {
+                extends: Some("stagging".to_string()),
+                ..Default::default()
+            },
+        ),
+        ("staging", ProfileOverride::default()),
+    ]);
+
+    let err = config.with_profile("preprod").unwrap_err();
+    assert_eq!(err.field(), Some("profiles.preprod.extends"));
+    let message = err.to_string();
+    assert!(message.contains("stagging"), "{}", message);
+    assert!(message.contains("preprod, staging"), "{}", message);
+}
+
+/// Three-level chains apply the outermost ancestor first.
+#[test]
+fn test_profile_chain_orders_ancestors_first() {
+    let config = config_with_profiles(vec![
+        (
+            "a",
+            ProfileOverride {
+                output_folder: Some("./a".to_string()),
+                ..Default::default()
+            },
+        ),
+        (
+            "b",
+            ProfileOverride {
+                extends: Some("a".to_string()),
+                output_folder: Some("./b".to_string()),
+                ..Default::default()
+            },
+        ),
+        (
+            "c",
+            ProfileOverride {
+                extends: Some("b".to_string()),
+                ..Default::default()
+            },
+        ),
+    ]);
+
+    assert_eq!(config.profile_chain("c").unwrap(), vec!["a", "b", "c"]);
+    assert_eq!(config.with_profile("c").unwrap().output_folder, "./b");
+}
+
+// =============================================================================
+// Merge strategies
+// =============================================================================
+
+/// A replacing section must name its endpoint, since nothing is inherited.
+#[test]
+fn test_replace_section_without_endpoint_is_rejected() {
+    let yaml = r#"
+project_name: "my-project"
+output_folder: "./output"
+jira:
+  endpoint: "https://jira.example.com"
+profiles:
+  dev:
+    jira:
+      token: "dev-token"
+"#;
+    let config: ProjectConfig = serde_yaml::from_str(yaml).unwrap();
+
+    let errors = config.validation_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].field(), Some("profiles.dev.jira.endpoint"));
+    assert!(errors[0].to_string().contains("merge: deep"));
+}
+
+/// Deep merge keeps base values the profile does not mention.
+#[test]
+fn test_apply_profile_deep_merges_sections() {
+    let base_config = config_with_profiles(Vec::new());
+    let base_config = ProjectConfig {
+        llm: Some(LlmConfig {
+            mode: LlmMode::Local,
+            local_endpoint: Some("http://localhost:11434".to_string()),
+            local_model: Some("mistral:7b".to_string()),
+            cloud_enabled: false,
+            cloud_endpoint: None,
+            cloud_model: None,
+            api_key: None,
+            timeout_seconds: 30,
+            max_tokens: 2048,
+            network: None,
+            resilience: None,
+        }),
+        ..base_config
+    };
+    let profile = ProfileOverride {
+        merge: Some(MergeStrategy::Deep),
+        llm: Some(LlmOverride {
+            local_model: Some("codellama:13b".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let llm = base_config.apply_profile(&profile).llm.unwrap();
+    assert_eq!(llm.local_model.as_deref(), Some("codellama:13b"));
+    assert_eq!(
+        llm.local_endpoint.as_deref(),
+        Some("http://localhost:11434")
+    );
+    assert_eq!(llm.timeout_seconds, 30);
+    assert_eq!(llm.max_tokens, 2048);
+}
+
+/// Partial overrides redact their secrets like full sections.
+#[test]
+fn test_partial_overrides_redact_secrets_in_debug() {
+    let profile = ProfileOverride {
+        squash: Some(SquashOverride {
+            password: Some("squash-partial-secret".to_string()),
+            ..Default::default()
+        }),
+        llm: Some(LlmOverride {
+            api_key: Some("sk-partial-secret".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let debug_str = format!("{:?}", profile);
+    assert!(!debug_str.contains("squash-partial-secret"));
+    assert!(!debug_str.contains("sk-partial-secret"));
+    assert!(debug_str.contains("[REDACTED]"));
+}
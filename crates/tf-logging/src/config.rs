@@ -16,14 +16,17 @@ pub struct LoggingConfig {
 impl LoggingConfig {
     /// Derive logging config from project configuration.
     ///
-    /// - `log_dir` = `"{output_folder}/logs"`, fallback to `"./logs"` if output_folder is empty
+    /// - `log_dir` = `"{output_folder}/logs"`, fallback to `"./logs"` if output_folder is empty.
+    ///   A relative `output_folder` is resolved against the config file directory
+    ///   (see [`ProjectConfig::resolve_path`]).
     /// - `log_level` defaults to `"info"`
     /// - `log_to_stdout` defaults to `false`
     pub fn from_project_config(config: &ProjectConfig) -> Self {
         let log_dir = if config.output_folder.is_empty() {
             "./logs".to_string()
         } else {
-            std::path::Path::new(&config.output_folder)
+            config
+                .output_folder_path()
                 .join("logs")
                 .to_string_lossy()
                 .to_string()
@@ -91,4 +94,23 @@ mod tests {
         // Should fallback to "./logs" when output_folder is empty
         assert_eq!(logging_config.log_dir, "./logs");
     }
+
+    #[test]
+    fn test_logging_config_resolves_relative_output_folder_against_config_dir() {
+        let temp = tempdir().unwrap();
+        let config_path = temp.path().join("config.yaml");
+        fs::write(
+            &config_path,
+            "project_name: \"test-project\"\noutput_folder: \"output\"\n",
+        )
+        .unwrap();
+
+        let project_config = tf_config::load_config(&config_path).unwrap();
+        let logging_config = LoggingConfig::from_project_config(&project_config);
+
+        assert_eq!(
+            std::path::PathBuf::from(&logging_config.log_dir),
+            temp.path().join("output").join("logs")
+        );
+    }
 }
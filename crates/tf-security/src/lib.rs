@@ -41,13 +41,13 @@
 //! # config.yaml
 //! jira:
 //!   token: ${SECRET:jira-token}
-//!   url: https://jira.example.com
+//!   endpoint: https://jira.example.com
 //! ```
 //!
 //! The `${SECRET:key_name}` syntax indicates that the value should be resolved
-//! from the OS keyring at runtime. This pattern is **documented but not yet
-//! implemented** - resolution will be added in a future story integrating
-//! tf-config with tf-security.
+//! from the OS keyring at runtime. Resolution is performed by
+//! `tf_config::load_config_with_secrets` (enable the `secret-store` feature of
+//! tf-config), which passes a `SecretStore` as the secret resolver.
 //!
 //! To store the secret referenced above:
 //! ```bash